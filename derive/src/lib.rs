@@ -0,0 +1,204 @@
+//! Derive macros for `rogue_logging`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, LitInt, LitStr, Result};
+
+const ATTRIBUTE: &str = "failure";
+const SEVERITIES: [&str; 3] = ["advice", "warning", "error"];
+
+/// Derive `rogue_logging::Action` with per-variant defaults for `Failure`.
+///
+/// Annotate enum variants, or a struct itself, with:
+///
+/// ```text
+/// #[failure(code = "E0001", help = "...", url = "...", severity = "warning")]
+/// #[failure(domain = "config", status_code = 400)]
+/// ```
+///
+/// Every key is optional. Severity is one of `advice`, `warning` or `error`.
+#[proc_macro_derive(Action, attributes(failure))]
+pub fn derive_action(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Values of a `#[failure(...)]` attribute.
+#[derive(Default)]
+struct Defaults {
+    code: Option<LitStr>,
+    help: Option<LitStr>,
+    url: Option<LitStr>,
+    severity: Option<LitStr>,
+    domain: Option<LitStr>,
+    status_code: Option<LitInt>,
+}
+
+/// A match arm pattern and the defaults it selects.
+struct Arm {
+    pattern: TokenStream2,
+    defaults: Defaults,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let arms = match &input.data {
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                Ok(Arm {
+                    pattern: quote!(Self::#ident { .. }),
+                    defaults: parse_defaults(&variant.attrs)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Data::Struct(_) => vec![Arm {
+            pattern: quote!(_),
+            defaults: parse_defaults(&input.attrs)?,
+        }],
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Action can only be derived for enums and structs",
+            ))
+        }
+    };
+    let code = expand_method(&arms, |defaults| {
+        defaults.code.as_ref().map(|code| quote!(#code.to_owned()))
+    });
+    let help = expand_method(&arms, |defaults| {
+        defaults.help.as_ref().map(|help| quote!(#help.to_owned()))
+    });
+    let url = expand_method(&arms, |defaults| {
+        defaults.url.as_ref().map(|url| quote!(#url.to_owned()))
+    });
+    let severity = expand_method(&arms, |defaults| {
+        defaults.severity.as_ref().map(expand_severity)
+    });
+    let domain = expand_method(&arms, |defaults| {
+        defaults
+            .domain
+            .as_ref()
+            .map(|domain| quote!(#domain.to_owned()))
+    });
+    let status_code = expand_method(&arms, |defaults| {
+        defaults
+            .status_code
+            .as_ref()
+            .map(|status_code| quote!(#status_code))
+    });
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rogue_logging::Action for #name #type_generics #where_clause {
+            #[allow(unreachable_patterns)]
+            fn code(&self) -> ::core::option::Option<::std::string::String> {
+                #code
+            }
+
+            #[allow(unreachable_patterns)]
+            fn help(&self) -> ::core::option::Option<::std::string::String> {
+                #help
+            }
+
+            #[allow(unreachable_patterns)]
+            fn url(&self) -> ::core::option::Option<::std::string::String> {
+                #url
+            }
+
+            #[allow(unreachable_patterns)]
+            fn severity(&self) -> ::core::option::Option<::rogue_logging::Severity> {
+                #severity
+            }
+
+            #[allow(unreachable_patterns)]
+            fn domain(&self) -> ::core::option::Option<::std::string::String> {
+                #domain
+            }
+
+            #[allow(unreachable_patterns)]
+            fn status_code(&self) -> ::core::option::Option<u16> {
+                #status_code
+            }
+        }
+    })
+}
+
+/// Expand the body of a method as a match over the arms with a value.
+fn expand_method(arms: &[Arm], value: impl Fn(&Defaults) -> Option<TokenStream2>) -> TokenStream2 {
+    let arms: Vec<TokenStream2> = arms
+        .iter()
+        .filter_map(|arm| {
+            let pattern = &arm.pattern;
+            value(&arm.defaults)
+                .map(|value| quote!(#pattern => ::core::option::Option::Some(#value),))
+        })
+        .collect();
+    if arms.is_empty() {
+        return quote!(::core::option::Option::None);
+    }
+    quote! {
+        match self {
+            #(#arms)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+
+fn expand_severity(severity: &LitStr) -> TokenStream2 {
+    match severity.value().as_str() {
+        "advice" => quote!(::rogue_logging::Severity::Advice),
+        "warning" => quote!(::rogue_logging::Severity::Warning),
+        _ => quote!(::rogue_logging::Severity::Error),
+    }
+}
+
+fn parse_defaults(attrs: &[Attribute]) -> Result<Defaults> {
+    let mut defaults = Defaults::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("status_code") {
+                if defaults.status_code.is_some() {
+                    return Err(Error::new(meta.path.span(), "duplicate key"));
+                }
+                let value: LitInt = meta.value()?.parse()?;
+                value.base10_parse::<u16>()?;
+                defaults.status_code = Some(value);
+                return Ok(());
+            }
+            let value: LitStr = meta.value()?.parse()?;
+            let slot = if meta.path.is_ident("code") {
+                &mut defaults.code
+            } else if meta.path.is_ident("help") {
+                &mut defaults.help
+            } else if meta.path.is_ident("url") {
+                &mut defaults.url
+            } else if meta.path.is_ident("domain") {
+                &mut defaults.domain
+            } else if meta.path.is_ident("severity") {
+                if !SEVERITIES.contains(&value.value().as_str()) {
+                    return Err(Error::new(
+                        value.span(),
+                        "severity must be one of `advice`, `warning` or `error`",
+                    ));
+                }
+                &mut defaults.severity
+            } else {
+                return Err(meta.error(
+                    "expected `code`, `help`, `url`, `severity`, `domain` or `status_code`",
+                ));
+            };
+            if slot.is_some() {
+                return Err(Error::new(meta.path.span(), "duplicate key"));
+            }
+            *slot = Some(value);
+            Ok(())
+        })?;
+    }
+    Ok(defaults)
+}
@@ -0,0 +1,74 @@
+//! Translatable message templates for displaying errors.
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+static CATALOG: RwLock<Option<Arc<dyn MessageCatalog>>> = RwLock::new(None);
+
+/// Templates for the text surrounding an error's own message.
+///
+/// Every method has an English default so a translation only needs to
+/// override the templates it changes.
+///
+/// Select a catalog at runtime with [`set_message_catalog`].
+pub trait MessageCatalog: Send + Sync {
+    /// The emphasized word passed to [`MessageCatalog::failed_to`].
+    ///
+    /// Default: `Failed`
+    fn failed(&self) -> String {
+        "Failed".to_owned()
+    }
+
+    /// Headline of an error.
+    ///
+    /// `failed` is the output of [`MessageCatalog::failed`], possibly styled.
+    ///
+    /// Default: `{failed} to {action}`
+    fn failed_to(&self, failed: &str, action: &str) -> String {
+        format!("{failed} to {action}")
+    }
+
+    /// Line describing the domain of an error.
+    ///
+    /// Default: `A {domain} error occurred`
+    fn domain_error(&self, domain: &str) -> String {
+        format!("A {domain} error occurred")
+    }
+
+    /// Line describing the HTTP status code of an error.
+    ///
+    /// Default: `A {status_code} error occurred`
+    fn status_code_error(&self, status_code: u16) -> String {
+        format!("A {status_code} error occurred")
+    }
+
+    /// Line introducing an underlying cause.
+    ///
+    /// Default: `Caused by: {cause}`
+    fn caused_by(&self, cause: &str) -> String {
+        format!("Caused by: {cause}")
+    }
+}
+
+/// The default English [`MessageCatalog`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnglishCatalog;
+
+impl MessageCatalog for EnglishCatalog {}
+
+/// Replace the [`MessageCatalog`] used to display errors.
+pub fn set_message_catalog(catalog: impl MessageCatalog + 'static) {
+    let mut current = CATALOG.write().unwrap_or_else(PoisonError::into_inner);
+    *current = Some(Arc::new(catalog));
+}
+
+/// Get the [`MessageCatalog`] used to display errors.
+///
+/// Default: [`EnglishCatalog`]
+#[must_use]
+pub fn get_message_catalog() -> Arc<dyn MessageCatalog> {
+    CATALOG
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_else(|| Arc::new(EnglishCatalog))
+}
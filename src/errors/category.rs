@@ -0,0 +1,98 @@
+//! Error categories with BSD `sysexits` style exit codes.
+
+use serde::{Deserialize, Serialize};
+use std::error::Error as StdError;
+use std::io::{Error as IoError, ErrorKind};
+use ErrorCategory::*;
+
+/// Broad classification of an error, used to choose a process exit code.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCategory {
+    /// The command was used incorrectly.
+    ///
+    /// Exit code: `64` (`EX_USAGE`)
+    Usage,
+    /// An input file or resource did not exist.
+    ///
+    /// Exit code: `66` (`EX_NOINPUT`)
+    NotFound,
+    /// Insufficient permission to perform the operation.
+    ///
+    /// Exit code: `77` (`EX_NOPERM`)
+    Permission,
+    /// An error occurred while doing I/O.
+    ///
+    /// Exit code: `74` (`EX_IOERR`)
+    Io,
+    /// A remote service was unavailable.
+    ///
+    /// Exit code: `69` (`EX_UNAVAILABLE`)
+    Network,
+    /// Something was found in an unconfigured or misconfigured state.
+    ///
+    /// Exit code: `78` (`EX_CONFIG`)
+    Config,
+    /// An internal software error was detected.
+    ///
+    /// Exit code: `70` (`EX_SOFTWARE`)
+    Internal,
+}
+
+impl ErrorCategory {
+    /// Get the BSD `sysexits` style exit code.
+    #[must_use]
+    pub fn exit_code(self) -> u8 {
+        match self {
+            Usage => 64,
+            NotFound => 66,
+            Network => 69,
+            Internal => 70,
+            Io => 74,
+            Permission => 77,
+            Config => 78,
+        }
+    }
+
+    /// Infer the category from an HTTP status code.
+    #[must_use]
+    pub fn from_status_code(status_code: u16) -> Option<Self> {
+        match status_code {
+            400 | 422 => Some(Usage),
+            401 | 403 => Some(Permission),
+            404 | 410 => Some(NotFound),
+            502..=504 => Some(Network),
+            _ => None,
+        }
+    }
+
+    /// Infer the category from an [`ErrorKind`].
+    #[must_use]
+    pub fn from_io_kind(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::NotFound => NotFound,
+            ErrorKind::PermissionDenied => Permission,
+            ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::AddrInUse
+            | ErrorKind::AddrNotAvailable
+            | ErrorKind::TimedOut => Network,
+            _ => Io,
+        }
+    }
+
+    /// Infer the category from the first [`IoError`] in an error or its source chain.
+    #[must_use]
+    pub fn from_source(error: &(dyn StdError + 'static)) -> Option<Self> {
+        let mut current = Some(error);
+        while let Some(error) = current {
+            if let Some(io_error) = error.downcast_ref::<IoError>() {
+                return Some(Self::from_io_kind(io_error.kind()));
+            }
+            current = error.source();
+        }
+        None
+    }
+}
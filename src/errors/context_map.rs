@@ -0,0 +1,41 @@
+//! Serialize key-value pairs as a map, preserving order.
+
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserializer, Serializer};
+use std::fmt::{Formatter, Result as FmtResult};
+
+pub(crate) fn serialize<S: Serializer>(
+    context: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(context.len()))?;
+    for (key, value) in context {
+        map.serialize_entry(key, value)?;
+    }
+    map.end()
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(String, String)>, D::Error> {
+    deserializer.deserialize_map(ContextVisitor)
+}
+
+struct ContextVisitor;
+
+impl<'de> Visitor<'de> for ContextVisitor {
+    type Value = Vec<(String, String)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a map of strings")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut context = Vec::with_capacity(access.size_hint().unwrap_or_default());
+        while let Some(entry) = access.next_entry()? {
+            context.push(entry);
+        }
+        Ok(context)
+    }
+}
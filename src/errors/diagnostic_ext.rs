@@ -1,19 +1,165 @@
-//! Extension trait for rendering diagnostics with miette's graphical output.
+//! Extension trait for rendering diagnostics.
 
-use miette::{Diagnostic, GraphicalReportHandler};
+#[cfg(feature = "log")]
+use super::{format_causes, get_causes};
+use super::{get_message_catalog, RenderMode, RenderOptions};
+#[cfg(feature = "log")]
+use log::{log, Level};
+#[cfg(feature = "log")]
+use miette::Severity;
+use miette::{Diagnostic, JSONReportHandler, NarratableReportHandler};
+use std::fmt::Write;
 
-/// Extension trait for rendering [`Diagnostic`] types with fancy output.
-pub trait DiagnosticExt {
-    /// Render the diagnostic using miette's graphical handler.
-    fn render(&self) -> String;
-}
-
-impl<T: Diagnostic> DiagnosticExt for T {
+/// Extension trait for rendering [`Diagnostic`] types.
+///
+/// Each method provides a default. Implemented for [`Failure`](crate::Failure),
+/// [`Failures`](crate::Failures), [`DiagnosticReport`](crate::DiagnosticReport)
+/// and [`Error`](crate::Error). Implement it for other diagnostics with
+/// [`impl_diagnostic_ext!`].
+///
+/// # Migration
+///
+/// `DiagnosticExt` was previously implemented for every [`Diagnostic`].
+/// Other diagnostic types opt in with [`impl_diagnostic_ext!`]:
+///
+/// ```text
+/// impl_diagnostic_ext!(ParseError, NetworkError);
+/// ```
+pub trait DiagnosticExt: Diagnostic + Sized {
+    /// Render the diagnostic using miette's graphical handler with default options.
+    ///
+    /// Falls back to the narratable handler without the `miette-fancy` feature.
     fn render(&self) -> String {
+        self.render_with(RenderMode::Graphical)
+    }
+
+    /// Render the diagnostic as prose suited to screen readers.
+    fn render_narratable(&self) -> String {
         let mut output = String::new();
-        GraphicalReportHandler::new()
+        NarratableReportHandler::new()
             .render_report(&mut output, self)
             .expect("diagnostic should render");
         output
     }
+
+    /// Render the diagnostic as a single line JSON object.
+    fn render_json(&self) -> String {
+        let mut output = String::new();
+        JSONReportHandler::new()
+            .render_report(&mut output, self)
+            .expect("diagnostic should render");
+        output
+    }
+
+    /// Render the diagnostic in the given format with default options.
+    fn render_with(&self, mode: RenderMode) -> String {
+        self.render_with_options(&RenderOptions {
+            render_mode: Some(mode),
+            ..RenderOptions::default()
+        })
+    }
+
+    /// Render the diagnostic with the given options.
+    fn render_with_options(&self, options: &RenderOptions) -> String {
+        match options.render_mode.unwrap_or_default() {
+            #[cfg(feature = "miette-fancy")]
+            RenderMode::Graphical => {
+                let mut output = String::new();
+                options
+                    .to_handler_with_footer(self.footer(options))
+                    .render_report(&mut output, self)
+                    .expect("diagnostic should render");
+                output
+            }
+            #[cfg(not(feature = "miette-fancy"))]
+            RenderMode::Graphical => self.render_narratable(),
+            RenderMode::Narratable => self.render_narratable(),
+            RenderMode::Json => self.render_json(),
+            RenderMode::Plain => render_plain(self),
+        }
+    }
+
+    /// Text displayed after [`RenderOptions::render_footer`] in graphical reports.
+    ///
+    /// Default: `None`
+    fn footer(&self, _options: &RenderOptions) -> Option<String> {
+        None
+    }
+
+    /// Log the diagnostic at the level of its severity with each source as
+    /// continuation lines.
+    #[cfg(feature = "log")]
+    fn log(&self) {
+        let level = get_level(self.severity());
+        for line in get_log_lines(self) {
+            log!(level, "{line}");
+        }
+    }
+}
+
+/// Implement [`DiagnosticExt`] with the default methods for each of the given types.
+///
+/// # Example
+///
+/// ```text
+/// impl_diagnostic_ext!(ParseError, NetworkError);
+/// ```
+#[macro_export]
+macro_rules! impl_diagnostic_ext {
+    ($($diagnostic:ty),+ $(,)?) => {
+        $(impl $crate::DiagnosticExt for $diagnostic {})+
+    };
+}
+
+/// Render the message and each source on its own line.
+fn render_plain<T: Diagnostic>(diagnostic: &T) -> String {
+    let catalog = get_message_catalog();
+    let mut output = diagnostic.to_string();
+    let mut current = diagnostic.source();
+    while let Some(cause) = current {
+        let lines = cause.to_string().replace('\n', "\n    ");
+        let _ = write!(output, "\n  {}", catalog.caused_by(&lines));
+        current = cause.source();
+    }
+    output.push('\n');
+    output
+}
+
+/// Map a diagnostic severity to a log level.
+#[cfg(feature = "log")]
+pub(crate) fn get_level(severity: Option<Severity>) -> Level {
+    match severity {
+        Some(Severity::Advice) => Level::Info,
+        Some(Severity::Warning) => Level::Warn,
+        Some(Severity::Error) | None => Level::Error,
+    }
+}
+
+/// Lines of the message followed by each source and each related diagnostic.
+#[cfg(feature = "log")]
+pub(crate) fn get_log_lines(diagnostic: &dyn Diagnostic) -> Vec<String> {
+    let catalog = get_message_catalog();
+    let mut lines: Vec<String> = diagnostic
+        .to_string()
+        .lines()
+        .map(ToOwned::to_owned)
+        .collect();
+    lines.extend(format_causes(
+        catalog.as_ref(),
+        &get_causes(diagnostic.source()),
+    ));
+    lines.extend(get_related_log_lines(diagnostic));
+    lines
+}
+
+/// Lines of each related diagnostic, indented.
+#[cfg(feature = "log")]
+pub(crate) fn get_related_log_lines(diagnostic: &dyn Diagnostic) -> Vec<String> {
+    diagnostic
+        .related()
+        .into_iter()
+        .flatten()
+        .flat_map(get_log_lines)
+        .map(|line| format!("  {line}"))
+        .collect()
 }
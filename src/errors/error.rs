@@ -1,9 +1,16 @@
 //! Serializable error type with logging support.
 
+#[cfg(feature = "miette")]
+use super::DiagnosticExt;
+use super::{
+    context_map, get_message_catalog, is_transient_status_code, ErrorCategory, MessageCatalog,
+};
 #[cfg(feature = "log")]
 use colored::Colorize;
 #[cfg(feature = "log")]
-use log::{error, trace};
+use log::{error, log_enabled, trace, Level};
+#[cfg(feature = "miette")]
+use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
 use std::backtrace::{Backtrace, BacktraceStatus};
 use std::error::Error as StdError;
@@ -48,9 +55,57 @@ pub struct Error {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_code: Option<u16>,
 
+    /// Broad classification used to choose a process exit code.
+    ///
+    /// If not set, it is inferred from the status code and domain.
+    ///
+    /// Example: `not-found`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<ErrorCategory>,
+
+    /// Whether the operation is worth retrying.
+    ///
+    /// If not set, it is inferred from the status code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retryable: Option<bool>,
+
+    /// Seconds to wait before retrying, rounded up.
+    ///
+    /// Example: `30`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+
+    /// Key-value pairs of additional context in insertion order.
+    ///
+    /// Each will be displayed as:
+    /// > ▷ {key}: {value}
+    ///
+    /// Example: `path`: `/etc/config.yaml`
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "context_map")]
+    pub context: Vec<(String, String)>,
+
+    /// Messages of the underlying errors that caused this error.
+    ///
+    /// Ordered from the direct cause to the root cause.
+    ///
+    /// Each will be displayed as:
+    /// > Caused by: {cause}
+    ///
+    /// Example: `expected ',' at line 3 column 12`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+
     /// Backtrace
     #[serde(skip)]
     pub backtrace: Option<Backtrace>,
+
+    /// Backtrace frames that survive serialization and cloning.
+    ///
+    /// Only populated by [`Error::with_backtrace_frames`] so payloads stay small by default.
+    ///
+    /// Example: `my_crate::load_config at ./src/config.rs:42:5`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backtrace_frames: Option<Vec<String>>,
 }
 
 impl Default for Error {
@@ -60,26 +115,65 @@ impl Default for Error {
             message: String::new(),
             domain: None,
             status_code: None,
+            category: None,
+            retryable: None,
+            retry_after: None,
+            context: Vec::new(),
+            causes: Vec::new(),
             backtrace: get_backtrace(),
+            backtrace_frames: None,
         }
     }
 }
 
 impl Error {
-    /// Format the error as separate lines.
+    /// Populate [`Error::backtrace_frames`] from the captured backtrace.
+    ///
+    /// Has no effect if no backtrace was captured.
+    #[must_use]
+    pub fn with_backtrace_frames(mut self) -> Self {
+        if let Some(backtrace) = &self.backtrace {
+            self.backtrace_frames = Some(get_frames(&backtrace.to_string()));
+        }
+        self
+    }
+
+    /// Get the category, inferring it from the status code and domain if not set.
+    ///
+    /// Falls back to [`ErrorCategory::Internal`].
+    #[must_use]
+    pub fn get_category(&self) -> ErrorCategory {
+        self.category
+            .or_else(|| self.status_code.and_then(ErrorCategory::from_status_code))
+            .or_else(|| (self.domain.as_deref() == Some("io")).then_some(ErrorCategory::Io))
+            .unwrap_or(ErrorCategory::Internal)
+    }
+
+    /// Whether the operation is worth retrying, inferring it from the status code if not set.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.retryable
+            .or_else(|| self.status_code.and_then(is_transient_status_code))
+            .unwrap_or_default()
+    }
+
+    /// Format the error as separate lines using the current [`MessageCatalog`].
     fn lines(&self) -> Vec<String> {
-        let mut lines = Vec::new();
-        #[cfg(feature = "log")]
-        lines.push(format!("{} to {}", "Failed".bold(), self.action));
-        #[cfg(not(feature = "log"))]
-        lines.push(format!("Failed to {}", self.action));
+        self.lines_with(get_message_catalog().as_ref())
+    }
+
+    /// Format the error as separate lines.
+    fn lines_with(&self, catalog: &dyn MessageCatalog) -> Vec<String> {
+        let mut lines = vec![format_headline(catalog, &self.action)];
         if let Some(domain) = &self.domain {
-            lines.push(format!("A {domain} error occurred"));
+            lines.push(catalog.domain_error(domain));
         }
-        if let Some(status_code) = &self.status_code {
-            lines.push(format!("A {status_code} error occurred"));
+        if let Some(status_code) = self.status_code {
+            lines.push(catalog.status_code_error(status_code));
         }
         lines.push(self.message.clone());
+        lines.extend(format_context_lines(&self.context));
+        lines.extend(format_causes(catalog, &self.causes));
         lines
     }
 
@@ -91,13 +185,26 @@ impl Error {
         }
         if let Some(backtrace) = &self.backtrace {
             trace!("Backtrace:\n{backtrace}");
+        } else if let Some(frames) = &self.backtrace_frames {
+            trace!("Backtrace:\n{}", format_frames(frames));
         }
     }
 
+    /// Whether [`Error::log`] would write anything with the current logger.
+    #[cfg(feature = "log")]
+    pub(crate) fn is_logged() -> bool {
+        log_enabled!(Level::Error)
+    }
+
     /// Multiline string representation of the error.
     pub fn display(&self) -> String {
         self.lines().join("\n")
     }
+
+    /// Multiline string representation of the error using a specific [`MessageCatalog`].
+    pub fn display_with(&self, catalog: &dyn MessageCatalog) -> String {
+        self.lines_with(catalog).join("\n")
+    }
 }
 
 impl Debug for Error {
@@ -114,6 +221,12 @@ impl Display for Error {
 
 impl StdError for Error {}
 
+#[cfg(feature = "miette")]
+impl Diagnostic for Error {}
+
+#[cfg(feature = "miette")]
+impl DiagnosticExt for Error {}
+
 impl Clone for Error {
     fn clone(&self) -> Self {
         Self {
@@ -121,16 +234,92 @@ impl Clone for Error {
             domain: self.domain.clone(),
             message: self.message.clone(),
             status_code: self.status_code,
+            category: self.category,
+            retryable: self.retryable,
+            retry_after: self.retry_after,
+            context: self.context.clone(),
+            causes: self.causes.clone(),
             backtrace: None,
+            backtrace_frames: self.backtrace_frames.clone(),
+        }
+    }
+}
+
+/// Format the headline of an action, emphasizing the failed word if logging.
+pub(crate) fn format_headline(catalog: &dyn MessageCatalog, action: &str) -> String {
+    let failed = catalog.failed();
+    #[cfg(feature = "log")]
+    let failed = failed.bold().to_string();
+    catalog.failed_to(&failed, action)
+}
+
+/// Format each key-value pair of context as a line, dimmed if logging.
+pub(crate) fn format_context_lines(context: &[(String, String)]) -> Vec<String> {
+    context
+        .iter()
+        .map(|(key, value)| {
+            let line = format!("▷ {key}: {value}");
+            #[cfg(feature = "log")]
+            let line = line.dimmed().to_string();
+            line
+        })
+        .collect()
+}
+
+/// Format each cause as an indented line with indented continuation lines.
+pub(crate) fn format_causes(catalog: &dyn MessageCatalog, causes: &[String]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for cause in causes {
+        let mut cause_lines = cause.lines();
+        let first = cause_lines.next().unwrap_or_default();
+        lines.push(format!("  {}", catalog.caused_by(first)));
+        for line in cause_lines {
+            lines.push(format!("    {line}"));
         }
     }
+    lines
 }
 
+/// Capture a backtrace if enabled by `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`.
 #[allow(clippy::wildcard_enum_match_arm)]
-fn get_backtrace() -> Option<Backtrace> {
+pub(crate) fn get_backtrace() -> Option<Backtrace> {
     let backtrace = Backtrace::capture();
     match backtrace.status() {
         BacktraceStatus::Captured => Some(backtrace),
         _ => None,
     }
 }
+
+/// Parse the frames from a rendered [`Backtrace`].
+///
+/// Each frame is formatted as `{symbol}` or `{symbol} at {location}`.
+pub(crate) fn get_frames(backtrace: &str) -> Vec<String> {
+    let mut frames: Vec<String> = Vec::new();
+    for line in backtrace.lines().map(str::trim) {
+        if let Some(location) = line.strip_prefix("at ") {
+            if let Some(frame) = frames.last_mut() {
+                frame.push_str(" at ");
+                frame.push_str(location);
+            }
+        } else if let Some((index, symbol)) = line.split_once(": ") {
+            if !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()) {
+                frames.push(symbol.to_owned());
+            }
+        }
+    }
+    frames
+}
+
+/// Format frames in the numbered style of a rendered [`Backtrace`].
+#[cfg_attr(
+    not(any(feature = "log", feature = "miette")),
+    expect(dead_code, reason = "used by logging and Failure rendering")
+)]
+pub(crate) fn format_frames(frames: &[String]) -> String {
+    frames
+        .iter()
+        .enumerate()
+        .map(|(index, frame)| format!("{index:>4}: {frame}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
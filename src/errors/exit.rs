@@ -0,0 +1,69 @@
+//! Report errors and exit with the exit code for their category.
+
+use super::Error;
+#[cfg(feature = "miette")]
+use super::{Action, DiagnosticExt, Failure};
+use std::process::exit;
+
+/// Extension trait to report the error of a [`Result`] and exit the process.
+///
+/// # Example
+///
+/// ```text
+/// fn main() {
+///     run().or_exit();
+/// }
+/// ```
+pub trait OrExit<T> {
+    /// Return the value, or report the error and exit with the exit code for its category.
+    fn or_exit(self) -> T;
+}
+
+impl<T> OrExit<T> for Result<T, Error> {
+    fn or_exit(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(error) => error.exit(),
+        }
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<T, A: Action> OrExit<T> for Result<T, Failure<A>> {
+    fn or_exit(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(failure) => failure.exit(),
+        }
+    }
+}
+
+impl Error {
+    /// Report the error and exit with the exit code for its category.
+    ///
+    /// The error is logged with [`Error::log`] if the `log` feature is enabled
+    /// and the logger is enabled for the error level, otherwise it is written
+    /// to stderr.
+    #[expect(clippy::print_stderr)]
+    pub fn exit(&self) -> ! {
+        let code = i32::from(self.get_category().exit_code());
+        #[cfg(feature = "log")]
+        if Self::is_logged() {
+            self.log();
+            log::logger().flush();
+            exit(code);
+        }
+        eprintln!("{}", self.display());
+        exit(code)
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<T: Action> Failure<T> {
+    /// Render the diagnostic to stderr and exit with the exit code for its category.
+    #[expect(clippy::print_stderr)]
+    pub fn exit(&self) -> ! {
+        eprint!("{}", self.render());
+        exit(i32::from(self.get_category().exit_code()))
+    }
+}
@@ -1,16 +1,105 @@
 //! Error wrapper implementing `miette::Diagnostic` for rich error reporting.
 
-use super::Error;
-use miette::{Diagnostic, Severity};
+#[cfg(feature = "log")]
+use super::{
+    format_causes, format_context_lines, format_headline, get_level, get_related_log_lines,
+};
+use super::{
+    format_frames, get_backtrace, get_causes, get_frames, get_message_catalog, is_transient_source,
+    is_transient_status_code, DiagnosticExt, Error, ErrorCategory, Problem, RenderOptions,
+};
+#[cfg(feature = "log")]
+use log::{log, trace};
+use miette::{Diagnostic, LabeledSpan, NamedSource, Severity, SourceCode, SourceSpan};
 use std::any::type_name;
+use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::path::Path;
+use std::time::Duration;
 
-/// Marker trait for action types that can be used with [`Failure`].
-pub trait Action: Debug + Display {}
+/// An action that can fail, used with [`Failure`].
+///
+/// The `Display` output completes the sentence "Failed to ...".
+///
+/// Each method provides a default that [`Failure`] uses when the builder
+/// hasn't set a value. Derive it with `#[derive(Action)]` using the `derive`
+/// feature, or implement it for plain enums with [`impl_action!`].
+///
+/// # Migration
+///
+/// `Action` was previously implemented for every `Debug + Display` type.
+/// Existing action types opt in with [`impl_action!`]:
+///
+/// ```text
+/// impl_action!(ReadAction, WriteAction);
+/// ```
+///
+/// Or, with the `derive` feature, by adding `#[derive(Action)]`, which needs
+/// no `#[failure(...)]` attributes for a plain enum.
+///
+/// `String` and `&str` still implement `Action` without changes.
+pub trait Action: Debug + Display {
+    /// Diagnostic code.
+    ///
+    /// Default: `None`, which falls back to `module::path::Action::Variant`
+    fn code(&self) -> Option<String> {
+        None
+    }
 
-impl<T: Debug + Display> Action for T {}
+    /// Help text.
+    ///
+    /// Default: `None`
+    fn help(&self) -> Option<String> {
+        None
+    }
+
+    /// URL for more information.
+    ///
+    /// Default: `None`
+    fn url(&self) -> Option<String> {
+        None
+    }
+
+    /// Severity level.
+    ///
+    /// Default: `None`, which is displayed as `Error`
+    fn severity(&self) -> Option<Severity> {
+        None
+    }
+
+    /// Domain in which this action fails.
+    ///
+    /// Default: `None`, which falls back to the type name of the action
+    fn domain(&self) -> Option<String> {
+        None
+    }
+
+    /// HTTP status code for a failure of this action.
+    ///
+    /// Default: `None`
+    fn status_code(&self) -> Option<u16> {
+        None
+    }
+}
+
+/// Implement [`Action`] with the default methods for each of the given types.
+///
+/// # Example
+///
+/// ```text
+/// impl_action!(ReadAction, WriteAction);
+/// ```
+#[macro_export]
+macro_rules! impl_action {
+    ($($action:ty),+ $(,)?) => {
+        $(impl $crate::Action for $action {})+
+    };
+}
+
+impl Action for String {}
+
+impl Action for &str {}
 
 /// A wrapper that implements [`miette::Diagnostic`] for rich error reporting.
 ///
@@ -23,13 +112,21 @@ pub struct Failure<T: Action> {
     help: Option<String>,
     url: Option<String>,
     severity: Option<Severity>,
+    category: Option<ErrorCategory>,
+    retryable: Option<bool>,
+    retry_after: Option<Duration>,
     related: Vec<BoxedDiagnostic>,
+    source_code: Option<NamedSource<String>>,
+    labels: Vec<LabeledSpan>,
     additional: Vec<(String, String)>,
     source: Option<BoxedError>,
+    backtrace: Option<Backtrace>,
 }
 
 impl<T: Action> Failure<T> {
     /// Create a new `Failure` with the given action and source error.
+    ///
+    /// A backtrace is captured if enabled by `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`.
     pub fn new(action: T, source: impl StdError + Send + Sync + 'static) -> Self {
         Self {
             action,
@@ -37,13 +134,21 @@ impl<T: Action> Failure<T> {
             help: None,
             url: None,
             severity: None,
+            category: None,
+            retryable: None,
+            retry_after: None,
             related: Vec::new(),
+            source_code: None,
+            labels: Vec::new(),
             additional: Vec::new(),
             source: Some(Box::new(source)),
+            backtrace: get_backtrace(),
         }
     }
 
     /// Create a new `Failure` with only an action, no source error.
+    ///
+    /// A backtrace is captured if enabled by `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`.
     pub fn from_action(action: T) -> Self {
         Self {
             action,
@@ -51,9 +156,15 @@ impl<T: Action> Failure<T> {
             help: None,
             url: None,
             severity: None,
+            category: None,
+            retryable: None,
+            retry_after: None,
             related: Vec::new(),
+            source_code: None,
+            labels: Vec::new(),
             additional: Vec::new(),
             source: None,
+            backtrace: get_backtrace(),
         }
     }
 
@@ -109,6 +220,27 @@ impl<T: Action> Failure<T> {
         &self.action
     }
 
+    /// Set the backtrace, replacing any captured on creation.
+    ///
+    /// Use with [`Backtrace::force_capture`] to capture regardless of the environment.
+    #[must_use]
+    pub fn with_backtrace(mut self, backtrace: Backtrace) -> Self {
+        self.backtrace = Some(backtrace);
+        self
+    }
+
+    /// Get the backtrace captured on creation.
+    #[must_use]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_ref()
+    }
+
+    /// Get the additional context in insertion order.
+    #[must_use]
+    pub fn context(&self) -> &[(String, String)] {
+        &self.additional
+    }
+
     /// Get a value by key from additional context.
     #[must_use]
     pub fn get(&self, key: &str) -> Option<String> {
@@ -144,7 +276,7 @@ impl<T: Action> Failure<T> {
 
     /// Set the diagnostic code.
     ///
-    /// Default: `module::path::Action::Variant`
+    /// Default: [`Action::code`], otherwise `module::path::Action::Variant`
     #[must_use]
     pub fn with_code(mut self, code: impl Into<String>) -> Self {
         self.code = Some(code.into());
@@ -153,7 +285,7 @@ impl<T: Action> Failure<T> {
 
     /// Set the help text.
     ///
-    /// Default: `None`
+    /// Default: [`Action::help`]
     #[must_use]
     pub fn with_help(mut self, help: impl Into<String>) -> Self {
         self.help = Some(help.into());
@@ -162,7 +294,7 @@ impl<T: Action> Failure<T> {
 
     /// Set the URL for more information.
     ///
-    /// Default: `None`
+    /// Default: [`Action::url`]
     #[must_use]
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Some(url.into());
@@ -171,13 +303,71 @@ impl<T: Action> Failure<T> {
 
     /// Set the severity level.
     ///
-    /// Default: `Error`
+    /// Default: [`Action::severity`], otherwise `Error`
     #[must_use]
     pub fn with_severity(mut self, severity: Severity) -> Self {
         self.severity = Some(severity);
         self
     }
 
+    /// Set the category.
+    ///
+    /// Default: inferred from the first [`std::io::Error`] in the source chain,
+    /// then [`Action::status_code`], otherwise [`ErrorCategory::Internal`]
+    #[must_use]
+    pub fn with_category(mut self, category: ErrorCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Get the category, inferring it from the source chain if not set.
+    #[must_use]
+    pub fn get_category(&self) -> ErrorCategory {
+        self.category
+            .or_else(|| self.source().and_then(ErrorCategory::from_source))
+            .or_else(|| {
+                self.action
+                    .status_code()
+                    .and_then(ErrorCategory::from_status_code)
+            })
+            .unwrap_or(ErrorCategory::Internal)
+    }
+
+    /// Set whether the operation is worth retrying.
+    ///
+    /// Default: inferred from the first [`std::io::Error`] in the source chain,
+    /// then [`Action::status_code`], otherwise `false`
+    #[must_use]
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
+    /// Set the duration to wait before retrying, marking the operation as retryable.
+    ///
+    /// Default: `None`
+    #[must_use]
+    pub fn with_retry_after(mut self, retry_after: Duration) -> Self {
+        self.retryable = Some(true);
+        self.retry_after = Some(retry_after);
+        self
+    }
+
+    /// Whether the operation is worth retrying, inferring it from the source chain if not set.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.retryable
+            .or_else(|| self.source().and_then(is_transient_source))
+            .or_else(|| self.action.status_code().and_then(is_transient_status_code))
+            .unwrap_or_default()
+    }
+
+    /// Get the duration to wait before retrying.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
     /// Add a related diagnostic.
     #[must_use]
     pub fn with_related(mut self, diagnostic: impl Diagnostic + Send + Sync + 'static) -> Self {
@@ -185,7 +375,88 @@ impl<T: Action> Failure<T> {
         self
     }
 
+    /// Set the source code that labels point into.
+    ///
+    /// The name is displayed above the snippet, typically a file path.
+    ///
+    /// Default: `None`
+    #[must_use]
+    pub fn with_source_code(mut self, name: impl AsRef<str>, text: impl Into<String>) -> Self {
+        self.source_code = Some(NamedSource::new(name, text.into()));
+        self
+    }
+
+    /// Add a secondary label pointing at a span of the source code.
+    ///
+    /// The span is a byte offset and length, or a range of byte offsets.
+    #[must_use]
+    pub fn with_label(mut self, span: impl Into<SourceSpan>, message: impl Into<String>) -> Self {
+        self.labels
+            .push(LabeledSpan::new_with_span(Some(message.into()), span));
+        self
+    }
+
+    /// Add the primary label pointing at a span of the source code.
+    ///
+    /// The span is a byte offset and length, or a range of byte offsets.
+    #[must_use]
+    pub fn with_primary_label(
+        mut self,
+        span: impl Into<SourceSpan>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.labels.push(LabeledSpan::new_primary_with_span(
+            Some(message.into()),
+            span,
+        ));
+        self
+    }
+
+    /// Log the failure at the level of its severity with backtrace at trace level.
+    ///
+    /// Context is dimmed and each source is logged as continuation lines.
+    #[cfg(feature = "log")]
+    pub fn log(&self) {
+        let level = get_level(self.severity());
+        for line in self.log_lines() {
+            log!(level, "{line}");
+        }
+        if let Some(backtrace) = &self.backtrace {
+            trace!("Backtrace:\n{backtrace}");
+        }
+    }
+
+    /// Get the related diagnostics added with [`Failure::with_related`].
+    pub(crate) fn get_related(
+        &self,
+    ) -> impl Iterator<Item = &(dyn Diagnostic + Send + Sync + 'static)> {
+        self.related.iter().map(AsRef::as_ref)
+    }
+
+    fn get_backtrace_frames(&self) -> Vec<String> {
+        self.backtrace
+            .as_ref()
+            .map(|backtrace| get_frames(&backtrace.to_string()))
+            .unwrap_or_default()
+    }
+
+    /// Format the failure as separate lines for logging.
+    ///
+    /// Related diagnostics follow as indented lines.
+    #[cfg(feature = "log")]
+    pub(crate) fn log_lines(&self) -> Vec<String> {
+        let catalog = get_message_catalog();
+        let mut lines = vec![format_headline(catalog.as_ref(), &self.action.to_string())];
+        lines.extend(format_context_lines(&self.additional));
+        lines.extend(format_causes(catalog.as_ref(), &get_causes(self.source())));
+        lines.extend(get_related_log_lines(self));
+        lines
+    }
+
     /// Convert to a serializable [`Error`].
+    ///
+    /// As with cloning an [`Error`], the captured backtrace is kept only as
+    /// [`Error::backtrace_frames`]. Use [`Failure::into_error`] to keep both.
     #[must_use]
     pub fn to_error(&self) -> Error {
         Error {
@@ -196,38 +467,77 @@ impl<T: Action> Failure<T> {
                 .map_or_else(String::new, ToString::to_string),
             domain: self
                 .get("domain")
+                .or_else(|| self.action.domain())
                 .or_else(|| Some(type_name::<T>().to_owned())),
-            status_code: None,
+            status_code: self.action.status_code(),
+            category: Some(self.get_category()),
+            retryable: Some(self.is_retryable()),
+            retry_after: self
+                .retry_after
+                .map(|duration| duration.as_secs() + u64::from(duration.subsec_nanos() > 0)),
+            context: self
+                .additional
+                .iter()
+                .filter(|(key, _)| key != "domain")
+                .cloned()
+                .collect(),
+            causes: get_causes(self.source().and_then(StdError::source)),
             backtrace: None,
+            backtrace_frames: self
+                .backtrace
+                .is_some()
+                .then(|| self.get_backtrace_frames()),
         }
     }
-}
 
-impl<T: Action> Failure<T> {
-    fn display_additional(&self) -> String {
-        self.additional
-            .iter()
-            .fold(String::new(), |mut acc, (k, v)| {
-                use std::fmt::Write;
-                let line = format!("▷ {k}: {v}");
-                #[cfg(feature = "miette-fancy")]
-                let line = {
-                    use owo_colors::{OwoColorize, Stream};
-                    line.if_supports_color(Stream::Stdout, |text| text.dimmed())
-                        .to_string()
-                };
-                let _ = write!(acc, "\n{line}");
-                acc
-            })
+    /// Convert into a serializable [`Error`], moving the captured backtrace.
+    ///
+    /// Both [`Error::backtrace`] and [`Error::backtrace_frames`] are populated
+    /// if a backtrace was captured.
+    #[must_use]
+    pub fn into_error(mut self) -> Error {
+        let mut error = self.to_error();
+        error.backtrace = self.backtrace.take();
+        error
     }
+
+    /// Convert to an HTTP [`Problem`] details document.
+    ///
+    /// The problem type is the URL, if set, and the diagnostic code is
+    /// included as an extension member.
+    #[must_use]
+    pub fn to_problem(&self) -> Problem {
+        let mut problem = Problem::from(self.to_error());
+        if let Some(url) = self.url.clone().or_else(|| self.action.url()) {
+            problem.problem_type = url;
+        }
+        problem.code = self.code().map(|code| code.to_string());
+        problem
+    }
+}
+
+/// Format context as dimmed lines, each preceded by a newline.
+pub(crate) fn format_context(context: &[(String, String)]) -> String {
+    context.iter().fold(String::new(), |mut acc, (k, v)| {
+        use std::fmt::Write;
+        let line = format!("▷ {k}: {v}");
+        #[cfg(feature = "miette-fancy")]
+        let line = {
+            use owo_colors::{OwoColorize, Stream};
+            line.if_supports_color(Stream::Stdout, |text| text.dimmed())
+                .to_string()
+        };
+        let _ = write!(acc, "\n{line}");
+        acc
+    })
 }
 
 impl<T: Action> Display for Failure<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "Failed to {}", self.action)?;
-        if !self.additional.is_empty() {
-            write!(f, "{}", self.display_additional())?;
-        }
+        let catalog = get_message_catalog();
+        let action = self.action.to_string();
+        write!(f, "{}", catalog.failed_to(&catalog.failed(), &action))?;
+        write!(f, "{}", format_context(&self.additional))?;
         Ok(())
     }
 }
@@ -255,12 +565,13 @@ impl<T: Action> Diagnostic for Failure<T> {
         Some(Box::new(
             self.code
                 .clone()
+                .or_else(|| self.action.code())
                 .unwrap_or_else(|| short_code::<T>(&self.action)),
         ))
     }
 
     fn severity(&self) -> Option<Severity> {
-        self.severity
+        self.severity.or_else(|| self.action.severity())
     }
 
     #[expect(
@@ -268,7 +579,10 @@ impl<T: Action> Diagnostic for Failure<T> {
         reason = "cast from boxed struct to trait object"
     )]
     fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
-        self.help.as_ref().map(|h| Box::new(Displayable(h)) as _)
+        match &self.help {
+            Some(help) => Some(Box::new(Displayable(help)) as _),
+            None => self.action.help().map(|help| Box::new(help) as _),
+        }
     }
 
     #[expect(
@@ -276,7 +590,26 @@ impl<T: Action> Diagnostic for Failure<T> {
         reason = "cast from boxed struct to trait object"
     )]
     fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
-        self.url.as_ref().map(|u| Box::new(Displayable(u)) as _)
+        match &self.url {
+            Some(url) => Some(Box::new(Displayable(url)) as _),
+            None => self.action.url().map(|url| Box::new(url) as _),
+        }
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from struct reference to trait object"
+    )]
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.source_code.as_ref().map(|s| s as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            None
+        } else {
+            Some(Box::new(self.labels.iter().cloned()))
+        }
     }
 
     #[expect(
@@ -292,6 +625,19 @@ impl<T: Action> Diagnostic for Failure<T> {
     }
 }
 
+impl<T: Action> DiagnosticExt for Failure<T> {
+    /// The captured backtrace, if enabled by [`RenderOptions::render_backtrace`].
+    fn footer(&self, options: &RenderOptions) -> Option<String> {
+        if !options.render_backtrace.unwrap_or_default() || self.backtrace.is_none() {
+            return None;
+        }
+        Some(format!(
+            "Backtrace:\n{}",
+            format_frames(&self.get_backtrace_frames())
+        ))
+    }
+}
+
 struct Displayable<'a, T: Display>(&'a T);
 
 impl<T: Display> Display for Displayable<'_, T> {
@@ -305,7 +651,7 @@ impl<T: Display> Display for Displayable<'_, T> {
 /// - Enum actions: `crate::EnumName::Variant`
 /// - Struct actions at crate root (`crate::Struct`): `crate::Struct`
 /// - Struct actions with a parent module (`crate::module::Struct`): `crate::module::Struct`
-fn short_code<T: Action>(action: &T) -> String {
+pub(crate) fn short_code<T: Action>(action: &T) -> String {
     let full = type_name::<T>();
     let segments: Vec<&str> = full.split("::").collect();
     let crate_name = segments.first().unwrap_or(&full);
@@ -342,6 +688,7 @@ mod tests {
         Read,
         Write,
     }
+    impl Action for SimpleEnum {}
     impl Display for SimpleEnum {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             match self {
@@ -355,6 +702,7 @@ mod tests {
     enum TupleEnum {
         Download(String),
     }
+    impl Action for TupleEnum {}
     impl Display for TupleEnum {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             match self {
@@ -367,6 +715,7 @@ mod tests {
     enum StructEnum {
         Connect { host: String },
     }
+    impl Action for StructEnum {}
     impl Display for StructEnum {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             match self {
@@ -379,6 +728,7 @@ mod tests {
     enum SingleVariant {
         Only,
     }
+    impl Action for SingleVariant {}
     impl Display for SingleVariant {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             write!(f, "only")
@@ -387,6 +737,7 @@ mod tests {
 
     #[derive(Debug)]
     struct UnitStruct;
+    impl Action for UnitStruct {}
     impl Display for UnitStruct {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             write!(f, "unit action")
@@ -397,6 +748,7 @@ mod tests {
     struct FieldStruct {
         _msg: String,
     }
+    impl Action for FieldStruct {}
     impl Display for FieldStruct {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             write!(f, "field action")
@@ -405,6 +757,7 @@ mod tests {
 
     #[derive(Debug)]
     struct TupleStruct(#[expect(dead_code)] String);
+    impl Action for TupleStruct {}
     impl Display for TupleStruct {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             write!(f, "tuple action")
@@ -0,0 +1,162 @@
+//! Aggregate of multiple failures reported as one diagnostic.
+
+use super::report::get_known_failure;
+use super::{
+    get_message_catalog, short_code, Action, DiagnosticExt, DiagnosticReport, Error, Failure,
+};
+use miette::Diagnostic;
+use serde::{Serialize, Serializer};
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An aggregate of [`Failure`]s and [`Error`]s for reporting all problems at once.
+///
+/// Renders as one diagnostic with each child as a related diagnostic, and
+/// serializes as a list of [`Error`]s.
+#[derive(Debug)]
+pub struct Failures<T: Action> {
+    action: T,
+    children: Vec<Child<T>>,
+}
+
+#[derive(Debug)]
+enum Child<T: Action> {
+    Failure(Failure<T>),
+    Error(Error),
+}
+
+impl<T: Action> Failures<T> {
+    /// Create an empty aggregate for the action that failed.
+    pub fn new(action: T) -> Self {
+        Self {
+            action,
+            children: Vec::new(),
+        }
+    }
+
+    /// Get the action.
+    #[must_use]
+    pub fn action(&self) -> &T {
+        &self.action
+    }
+
+    /// Add a [`Failure`].
+    pub fn push(&mut self, failure: Failure<T>) {
+        self.children.push(Child::Failure(failure));
+    }
+
+    /// Add an [`Error`].
+    pub fn push_error(&mut self, error: Error) {
+        self.children.push(Child::Error(error));
+    }
+
+    /// Number of failures and errors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Whether there are no failures or errors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Return `Ok` if empty, otherwise `Err` with the aggregate.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Convert each child to a serializable [`Error`].
+    #[must_use]
+    pub fn to_errors(&self) -> Vec<Error> {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Child::Failure(failure) => failure.to_error(),
+                Child::Error(error) => error.clone(),
+            })
+            .collect()
+    }
+}
+
+impl<T: Action + 'static> Failures<T> {
+    /// Convert to a serializable [`DiagnosticReport`] with a related report
+    /// for each child.
+    #[must_use]
+    pub fn to_report(&self) -> DiagnosticReport {
+        let related = self
+            .children
+            .iter()
+            .map(|child| match child {
+                Child::Failure(failure) => failure.to_report(),
+                Child::Error(error) => DiagnosticReport::from_diagnostic(error),
+            })
+            .collect();
+        DiagnosticReport::from_fields(
+            self,
+            self.to_string(),
+            Vec::new(),
+            related,
+            get_known_failure::<T>,
+        )
+    }
+}
+
+impl<T: Action> Extend<Failure<T>> for Failures<T> {
+    fn extend<I: IntoIterator<Item = Failure<T>>>(&mut self, iter: I) {
+        self.children.extend(iter.into_iter().map(Child::Failure));
+    }
+}
+
+impl<T: Action> Extend<Error> for Failures<T> {
+    fn extend<I: IntoIterator<Item = Error>>(&mut self, iter: I) {
+        self.children.extend(iter.into_iter().map(Child::Error));
+    }
+}
+
+impl<T: Action> Display for Failures<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let catalog = get_message_catalog();
+        let action = self.action.to_string();
+        write!(f, "{}", catalog.failed_to(&catalog.failed(), &action))
+    }
+}
+
+impl<T: Action> StdError for Failures<T> {}
+
+impl<T: Action> Diagnostic for Failures<T> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(
+            self.action
+                .code()
+                .unwrap_or_else(|| short_code::<T>(&self.action)),
+        ))
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from struct reference to trait object"
+    )]
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.children.is_empty() {
+            return None;
+        }
+        Some(Box::new(self.children.iter().map(|child| match child {
+            Child::Failure(failure) => failure as &dyn Diagnostic,
+            Child::Error(error) => error as &dyn Diagnostic,
+        })))
+    }
+}
+
+impl<T: Action> DiagnosticExt for Failures<T> {}
+
+impl<T: Action> Serialize for Failures<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_errors().serialize(serializer)
+    }
+}
@@ -1,17 +1,60 @@
 //! Error types for structured error handling and reporting.
 
-#[cfg(feature = "miette-fancy")]
+mod catalog;
+mod category;
+mod context_map;
+#[cfg(feature = "miette")]
 mod diagnostic_ext;
 mod error;
+mod exit;
 #[cfg(feature = "miette")]
 mod failure;
+#[cfg(feature = "miette")]
+mod failures;
+mod problem;
+#[cfg(feature = "miette")]
+mod registry;
+#[cfg(feature = "miette")]
+mod render_mode;
+#[cfg(feature = "miette")]
+mod render_options;
+#[cfg(feature = "miette")]
+mod report;
+mod retry;
+#[cfg(all(feature = "log", feature = "miette"))]
+mod retry_policy;
+#[cfg(feature = "miette")]
+mod source_location;
 #[cfg(test)]
 mod tests;
+mod to_error;
 
-#[cfg(feature = "miette-fancy")]
+pub use catalog::*;
+pub use category::*;
+#[cfg(feature = "miette")]
 pub use diagnostic_ext::*;
 pub use error::*;
+pub use exit::*;
 #[cfg(feature = "miette")]
 pub use failure::*;
 #[cfg(feature = "miette")]
+pub use failures::*;
+#[cfg(feature = "miette")]
 pub use miette::Severity;
+pub use problem::*;
+#[cfg(feature = "miette")]
+pub use registry::*;
+#[cfg(feature = "miette")]
+pub use render_mode::*;
+#[cfg(feature = "miette")]
+pub use render_options::*;
+#[cfg(feature = "miette")]
+pub use report::*;
+pub use retry::*;
+#[cfg(all(feature = "log", feature = "miette"))]
+pub use retry_policy::*;
+#[cfg(feature = "derive")]
+pub use rogue_logging_derive::Action;
+#[cfg(feature = "miette")]
+pub use source_location::*;
+pub use to_error::*;
@@ -0,0 +1,303 @@
+//! HTTP problem details (RFC 9457) representation of [`Error`].
+
+use super::{get_message_catalog, Error, ErrorCategory};
+use serde::de::{Error as DeError, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+const ABOUT_BLANK: &str = "about:blank";
+
+/// Stands in for the action when matching a title against the catalog.
+const ACTION_PLACEHOLDER: &str = "\u{0}";
+
+/// Members of [`Problem`] that an extension must not overwrite.
+const RESERVED_MEMBERS: [&str; 11] = [
+    "type",
+    "title",
+    "status",
+    "detail",
+    "instance",
+    "code",
+    "domain",
+    "category",
+    "retryable",
+    "retry_after",
+    "causes",
+];
+
+/// An HTTP problem details document as defined by RFC 9457.
+///
+/// Serialize with a JSON serializer to produce an `application/problem+json` body.
+///
+/// Extension members are represented as strings. When deserializing, booleans
+/// and numbers are converted to strings while `null`, arrays and objects are
+/// skipped.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Problem {
+    /// URI reference identifying the problem type.
+    ///
+    /// Default: `about:blank`
+    #[serde(rename = "type", default = "about_blank")]
+    pub problem_type: String,
+
+    /// Short, human-readable summary of the problem type.
+    ///
+    /// Example: `Failed to deserialize object`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// HTTP status code.
+    ///
+    /// Example: `404`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+
+    /// Human-readable explanation specific to this occurrence of the problem.
+    ///
+    /// Example: `Object is not valid.`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /// URI reference identifying this occurrence of the problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+
+    /// Extension member for the diagnostic code.
+    ///
+    /// Example: `my_crate::Action::Deserialize`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// Extension member for the [`Error::domain`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Extension member for the [`Error::category`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<ErrorCategory>,
+
+    /// Extension member for the [`Error::retryable`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retryable: Option<bool>,
+
+    /// Extension member for the [`Error::retry_after`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+
+    /// Extension member for the [`Error::causes`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+
+    /// Extension members from the [`Error::context`].
+    ///
+    /// Keys that collide with a member of the problem, such as `type` or
+    /// `status`, are not serialized.
+    #[serde(
+        flatten,
+        serialize_with = "serialize_extensions",
+        deserialize_with = "deserialize_extensions"
+    )]
+    pub extensions: Vec<(String, String)>,
+}
+
+impl Problem {
+    /// Media type of a JSON problem details document.
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+}
+
+impl Default for Problem {
+    fn default() -> Self {
+        Self {
+            problem_type: about_blank(),
+            title: None,
+            status: None,
+            detail: None,
+            instance: None,
+            code: None,
+            domain: None,
+            category: None,
+            retryable: None,
+            retry_after: None,
+            causes: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+}
+
+impl From<&Error> for Problem {
+    fn from(error: &Error) -> Self {
+        Self {
+            title: Some(get_title(&error.action)),
+            status: error.status_code,
+            detail: Some(error.message.clone()),
+            domain: error.domain.clone(),
+            category: error.category,
+            retryable: error.retryable,
+            retry_after: error.retry_after,
+            causes: error.causes.clone(),
+            extensions: error.context.clone(),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<Error> for Problem {
+    fn from(error: Error) -> Self {
+        Self::from(&error)
+    }
+}
+
+impl From<Problem> for Error {
+    fn from(problem: Problem) -> Self {
+        Self {
+            action: get_action(&problem.title.unwrap_or_default()),
+            message: problem.detail.unwrap_or_default(),
+            domain: problem.domain,
+            status_code: problem.status,
+            category: problem.category,
+            retryable: problem.retryable,
+            retry_after: problem.retry_after,
+            context: problem.extensions,
+            causes: problem.causes,
+            backtrace: None,
+            backtrace_frames: None,
+        }
+    }
+}
+
+/// Headline of an action using the active [`MessageCatalog`](super::MessageCatalog).
+fn get_title(action: &str) -> String {
+    let catalog = get_message_catalog();
+    catalog.failed_to(&catalog.failed(), action)
+}
+
+/// Extract the action from a title produced by [`get_title`].
+///
+/// Falls back to the whole title if it doesn't match the active catalog.
+fn get_action(title: &str) -> String {
+    let catalog = get_message_catalog();
+    let template = catalog.failed_to(&catalog.failed(), ACTION_PLACEHOLDER);
+    template
+        .split_once(ACTION_PLACEHOLDER)
+        .and_then(|(prefix, suffix)| title.strip_prefix(prefix)?.strip_suffix(suffix))
+        .map_or_else(|| title.to_owned(), ToOwned::to_owned)
+}
+
+fn about_blank() -> String {
+    ABOUT_BLANK.to_owned()
+}
+
+fn serialize_extensions<S: Serializer>(
+    extensions: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+    for (key, value) in extensions {
+        if !RESERVED_MEMBERS.contains(&key.as_str()) {
+            map.serialize_entry(key, value)?;
+        }
+    }
+    map.end()
+}
+
+fn deserialize_extensions<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(String, String)>, D::Error> {
+    deserializer.deserialize_map(ExtensionsVisitor)
+}
+
+struct ExtensionsVisitor;
+
+impl<'de> Visitor<'de> for ExtensionsVisitor {
+    type Value = Vec<(String, String)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a map of extension members")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut extensions = Vec::with_capacity(access.size_hint().unwrap_or_default());
+        while let Some((key, ExtensionValue(value))) = access.next_entry()? {
+            if let Some(value) = value {
+                extensions.push((key, value));
+            }
+        }
+        Ok(extensions)
+    }
+}
+
+/// Value of an extension member, if it can be represented as a string.
+struct ExtensionValue(Option<String>);
+
+impl<'de> Deserialize<'de> for ExtensionValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ExtensionValueVisitor)
+    }
+}
+
+struct ExtensionValueVisitor;
+
+impl ExtensionValueVisitor {
+    #[expect(
+        clippy::unnecessary_wraps,
+        reason = "shared by visitor methods returning Result"
+    )]
+    fn some<E: DeError>(value: impl Display) -> Result<ExtensionValue, E> {
+        Ok(ExtensionValue(Some(value.to_string())))
+    }
+}
+
+impl<'de> Visitor<'de> for ExtensionValueVisitor {
+    type Value = ExtensionValue;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: DeError>(self, value: bool) -> Result<Self::Value, E> {
+        Self::some(value)
+    }
+
+    fn visit_i64<E: DeError>(self, value: i64) -> Result<Self::Value, E> {
+        Self::some(value)
+    }
+
+    fn visit_u64<E: DeError>(self, value: u64) -> Result<Self::Value, E> {
+        Self::some(value)
+    }
+
+    fn visit_f64<E: DeError>(self, value: f64) -> Result<Self::Value, E> {
+        Self::some(value)
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        Self::some(value)
+    }
+
+    fn visit_string<E: DeError>(self, value: String) -> Result<Self::Value, E> {
+        Ok(ExtensionValue(Some(value)))
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(ExtensionValue(None))
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(ExtensionValue(None))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        ExtensionValue::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, access: A) -> Result<Self::Value, A::Error> {
+        IgnoredAny.visit_seq(access)?;
+        Ok(ExtensionValue(None))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, access: A) -> Result<Self::Value, A::Error> {
+        IgnoredAny.visit_map(access)?;
+        Ok(ExtensionValue(None))
+    }
+}
@@ -0,0 +1,166 @@
+//! Registry of stable diagnostic codes for generating an error index.
+
+use super::{get_message_catalog, short_code, Action};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// A registered action and its diagnostic metadata.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CodeEntry {
+    /// Stable diagnostic code from [`Action::code`].
+    ///
+    /// Falls back to the unstable `module::path::Action::Variant`.
+    ///
+    /// Example: `E0001`
+    pub code: String,
+
+    /// Path of the action type and variant.
+    ///
+    /// Example: `my_crate::Action::ReadConfig`
+    pub name: String,
+
+    /// Displayed headline of the action.
+    ///
+    /// Example: `Failed to read config`
+    pub action: String,
+
+    /// Help text from [`Action::help`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    /// URL from [`Action::url`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A diagnostic code registered by more than one action.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicateCode {
+    /// The duplicated code.
+    pub code: String,
+
+    /// Names of every action registering the code.
+    pub names: Vec<String>,
+}
+
+/// A registry of the diagnostic codes of every action.
+///
+/// Register each action variant, then check for duplicates in a test and
+/// export the registry to build an error index.
+///
+/// # Example
+///
+/// ```text
+/// #[test]
+/// fn codes_are_unique() {
+///     let registry = CodeRegistry::new()
+///         .with(Action::ReadConfig)
+///         .with(Action::WriteConfig);
+///     registry.assert_unique();
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct CodeRegistry {
+    entries: Vec<CodeEntry>,
+}
+
+impl CodeRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an action.
+    #[must_use]
+    pub fn with<T: Action>(mut self, action: T) -> Self {
+        self.register(&action);
+        self
+    }
+
+    /// Register an action.
+    ///
+    /// Registering the same action variant again has no effect.
+    pub fn register<T: Action>(&mut self, action: &T) {
+        let name = short_code::<T>(action);
+        if self.entries.iter().any(|entry| entry.name == name) {
+            return;
+        }
+        let catalog = get_message_catalog();
+        self.entries.push(CodeEntry {
+            code: action.code().unwrap_or_else(|| name.clone()),
+            action: catalog.failed_to(&catalog.failed(), &action.to_string()),
+            name,
+            help: action.help(),
+            url: action.url(),
+        });
+    }
+
+    /// Get the registered entries in registration order.
+    #[must_use]
+    pub fn entries(&self) -> &[CodeEntry] {
+        &self.entries
+    }
+
+    /// Find codes registered by more than one action.
+    #[must_use]
+    pub fn duplicates(&self) -> Vec<DuplicateCode> {
+        let mut duplicates: Vec<DuplicateCode> = Vec::new();
+        for entry in &self.entries {
+            if let Some(duplicate) = duplicates.iter_mut().find(|d| d.code == entry.code) {
+                duplicate.names.push(entry.name.clone());
+                continue;
+            }
+            duplicates.push(DuplicateCode {
+                code: entry.code.clone(),
+                names: vec![entry.name.clone()],
+            });
+        }
+        duplicates.retain(|duplicate| duplicate.names.len() > 1);
+        duplicates
+    }
+
+    /// Panic if any code is registered by more than one action.
+    ///
+    /// Intended to be called from a test.
+    #[expect(clippy::panic, reason = "used as a test assertion")]
+    pub fn assert_unique(&self) {
+        let duplicates = self.duplicates();
+        if duplicates.is_empty() {
+            return;
+        }
+        let lines: Vec<String> = duplicates
+            .iter()
+            .map(|duplicate| format!("{}: {}", duplicate.code, duplicate.names.join(", ")))
+            .collect();
+        panic!("Duplicate diagnostic codes:\n{}", lines.join("\n"));
+    }
+
+    /// Export the registry as a JSON array of entries.
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.entries).expect("registry should serialize")
+    }
+
+    /// Export the registry as a Markdown table.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::from("| Code | Action | Help |\n| --- | --- | --- |\n");
+        for entry in &self.entries {
+            let code = match &entry.url {
+                Some(url) => format!("[`{}`]({url})", entry.code),
+                None => format!("`{}`", entry.code),
+            };
+            let help = entry.help.as_deref().map(escape).unwrap_or_default();
+            let _ = writeln!(output, "| {code} | {} | {help} |", escape(&entry.action));
+        }
+        output
+    }
+}
+
+/// Escape a Markdown table cell.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
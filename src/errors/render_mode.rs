@@ -0,0 +1,27 @@
+//! Output format options for rendering diagnostics.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Output format for rendering a diagnostic.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderMode {
+    /// Boxed report with source snippets, colored when supported.
+    ///
+    /// Falls back to [`RenderMode::Narratable`] without the `miette-fancy` feature.
+    #[default]
+    Graphical,
+    /// Prose report suited to screen readers.
+    ///
+    /// Example: `Diagnostic severity: error`
+    Narratable,
+    /// Single line JSON object for machine consumption.
+    ///
+    /// Example: `{"message": "Failed to read config", ...}`
+    Json,
+    /// Message followed by the source chain without decoration.
+    ///
+    /// Example: `Caused by: file not found`
+    Plain,
+}
@@ -0,0 +1,124 @@
+//! Configuration options for rendering diagnostics.
+
+use super::RenderMode;
+use clap::{Args, ValueEnum};
+#[cfg(all(feature = "miette-fancy", feature = "log"))]
+use colored::control::SHOULD_COLORIZE;
+#[cfg(feature = "miette-fancy")]
+use miette::{GraphicalReportHandler, GraphicalTheme};
+use serde::{Deserialize, Serialize};
+#[cfg(all(feature = "miette-fancy", not(feature = "log")))]
+use std::env::var_os;
+#[cfg(all(feature = "miette-fancy", not(feature = "log")))]
+use std::io::{stderr, IsTerminal};
+
+/// Characters used to draw graphical reports.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderTheme {
+    /// Box drawing characters and arrows.
+    ///
+    /// Example: `╰─▶`
+    #[default]
+    Unicode,
+    /// Plain ASCII characters.
+    ///
+    /// Example: `` `-> ``
+    Ascii,
+}
+
+/// Configuration options for rendering diagnostics.
+///
+/// Colors follow the logger: they are used only if it is colorizing.
+#[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RenderOptions {
+    /// Output format for diagnostics.
+    ///
+    /// Default: `graphical`
+    #[arg(long, value_enum)]
+    pub render_mode: Option<RenderMode>,
+
+    /// Width in columns to wrap graphical reports at.
+    ///
+    /// Default: `200`
+    #[arg(long)]
+    pub render_width: Option<usize>,
+
+    /// Characters used to draw graphical reports.
+    ///
+    /// Default: `unicode`
+    #[arg(long, value_enum)]
+    pub render_theme: Option<RenderTheme>,
+
+    /// Number of spaces to replace tabs with in source snippets.
+    ///
+    /// Default: `4`
+    #[arg(long)]
+    pub render_tab_width: Option<usize>,
+
+    /// Number of lines of source code shown around each label.
+    ///
+    /// Default: `1`
+    #[arg(long)]
+    pub render_context_lines: Option<usize>,
+
+    /// Text displayed after every graphical report.
+    #[arg(long)]
+    pub render_footer: Option<String>,
+
+    /// Display URLs as terminal hyperlinks.
+    ///
+    /// Default: `true` if colors are used
+    #[arg(long)]
+    pub render_links: Option<bool>,
+
+    /// Append the backtrace of a [`Failure`](crate::Failure) to the footer, if one was captured.
+    ///
+    /// Default: `false`
+    #[arg(long)]
+    pub render_backtrace: Option<bool>,
+}
+
+impl RenderOptions {
+    /// Create a graphical report handler from the options.
+    #[cfg(feature = "miette-fancy")]
+    #[must_use]
+    pub fn to_handler(&self) -> GraphicalReportHandler {
+        self.to_handler_with_footer(None)
+    }
+
+    /// Create a graphical report handler with text displayed after the footer.
+    #[cfg(feature = "miette-fancy")]
+    pub(crate) fn to_handler_with_footer(&self, extra: Option<String>) -> GraphicalReportHandler {
+        let colorize = should_colorize();
+        let theme = match (self.render_theme.unwrap_or_default(), colorize) {
+            (RenderTheme::Unicode, true) => GraphicalTheme::unicode(),
+            (RenderTheme::Unicode, false) => GraphicalTheme::unicode_nocolor(),
+            (RenderTheme::Ascii, true) => GraphicalTheme::ascii(),
+            (RenderTheme::Ascii, false) => GraphicalTheme::none(),
+        };
+        let handler = GraphicalReportHandler::new_themed(theme)
+            .with_width(self.render_width.unwrap_or(200))
+            .tab_width(self.render_tab_width.unwrap_or(4))
+            .with_context_lines(self.render_context_lines.unwrap_or(1))
+            .with_links(self.render_links.unwrap_or(colorize));
+        let footer: Vec<String> = self.render_footer.iter().cloned().chain(extra).collect();
+        if footer.is_empty() {
+            handler
+        } else {
+            handler.with_footer(footer.join("\n\n"))
+        }
+    }
+}
+
+/// Whether the logger is colorizing output.
+#[cfg(all(feature = "miette-fancy", feature = "log"))]
+fn should_colorize() -> bool {
+    SHOULD_COLORIZE.should_colorize()
+}
+
+/// Whether stderr is a terminal and `NO_COLOR` is unset.
+#[cfg(all(feature = "miette-fancy", not(feature = "log")))]
+fn should_colorize() -> bool {
+    var_os("NO_COLOR").is_none() && stderr().is_terminal()
+}
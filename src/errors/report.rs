@@ -0,0 +1,435 @@
+//! Serializable snapshot of a diagnostic report.
+
+use super::{
+    context_map, format_context, get_message_catalog, Action, DiagnosticExt, Failure, Failures,
+};
+use miette::{
+    Diagnostic, LabeledSpan, MietteError, MietteSpanContents, Severity, SourceCode, SourceSpan,
+    SpanContents,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A serializable snapshot of a [`Diagnostic`] including its source chain
+/// and related diagnostics.
+///
+/// Produced with [`Failure::to_report`], [`Failures::to_report`] or
+/// [`DiagnosticReport::from_diagnostic`] and rendered like the original once deserialized, so a report produced in
+/// one process can be displayed by another.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DiagnosticReport {
+    /// Headline of the diagnostic.
+    ///
+    /// Example: `Failed to read config`
+    pub message: String,
+
+    /// Key-value pairs of additional context in insertion order.
+    ///
+    /// Each will be displayed as:
+    /// > ▷ {key}: {value}
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "context_map")]
+    pub context: Vec<(String, String)>,
+
+    /// Diagnostic code.
+    ///
+    /// Example: `my_crate::Action::ReadConfig`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// Severity level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+
+    /// Help text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    /// URL for more information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Source code that the labels point into.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_code: Option<ReportSource>,
+
+    /// Labels pointing at spans of the source code.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<LabeledSpan>,
+
+    /// Underlying errors, from the direct cause to the root cause.
+    ///
+    /// Each is serialized as its message, or with its context if it has any.
+    #[serde(
+        default,
+        rename = "causes",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_causes",
+        deserialize_with = "deserialize_causes"
+    )]
+    pub source: Option<Box<Cause>>,
+
+    /// Related diagnostics.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<DiagnosticReport>,
+}
+
+/// Source code of a [`DiagnosticReport`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ReportSource {
+    /// Name displayed above the snippet, typically a file path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Full text of the source code.
+    pub text: String,
+}
+
+/// An error in the source chain of a [`DiagnosticReport`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cause {
+    /// Message of the underlying error.
+    pub message: String,
+
+    /// Key-value pairs of additional context in insertion order.
+    pub context: Vec<(String, String)>,
+
+    /// The next underlying error.
+    pub source: Option<Box<Cause>>,
+}
+
+impl DiagnosticReport {
+    /// Capture every field of a diagnostic, recursing into its source chain
+    /// and related diagnostics.
+    ///
+    /// The message is the `Display` output of the diagnostic without styling.
+    /// Use [`Failure::to_report`] or [`Failures::to_report`] to also capture
+    /// the context of nested failures with the same action type.
+    #[must_use]
+    pub fn from_diagnostic(diagnostic: &dyn Diagnostic) -> Self {
+        Self::from_fields(
+            diagnostic,
+            strip_ansi(&diagnostic.to_string()),
+            Vec::new(),
+            diagnostic
+                .related()
+                .map(|related| related.map(Self::from_diagnostic).collect())
+                .unwrap_or_default(),
+            get_known_report,
+        )
+    }
+
+    /// Capture the fields of a diagnostic with the given message, context
+    /// and related reports.
+    pub(crate) fn from_fields(
+        diagnostic: &dyn Diagnostic,
+        message: String,
+        context: Vec<(String, String)>,
+        related: Vec<DiagnosticReport>,
+        known: KnownReport,
+    ) -> Self {
+        Self {
+            message,
+            context,
+            code: diagnostic.code().map(|code| code.to_string()),
+            severity: diagnostic.severity(),
+            help: diagnostic.help().map(|help| help.to_string()),
+            url: diagnostic.url().map(|url| url.to_string()),
+            source_code: diagnostic.source_code().and_then(ReportSource::read),
+            labels: diagnostic
+                .labels()
+                .map(Iterator::collect)
+                .unwrap_or_default(),
+            source: get_cause(diagnostic.source(), known),
+            related,
+        }
+    }
+}
+
+impl<T: Action + 'static> Failure<T> {
+    /// Convert to a serializable [`DiagnosticReport`].
+    ///
+    /// Context is captured as key-value pairs, including that of nested
+    /// failures with the same action type.
+    #[must_use]
+    pub fn to_report(&self) -> DiagnosticReport {
+        let catalog = get_message_catalog();
+        let action = self.action().to_string();
+        let related = self
+            .get_related()
+            .map(|diagnostic| {
+                get_known_failure::<T>(diagnostic)
+                    .unwrap_or_else(|| DiagnosticReport::from_diagnostic(diagnostic))
+            })
+            .collect();
+        DiagnosticReport::from_fields(
+            self,
+            catalog.failed_to(&catalog.failed(), &action),
+            self.context().to_vec(),
+            related,
+            get_known_failure::<T>,
+        )
+    }
+}
+
+/// Capture a nested error as a report if its type is known.
+pub(crate) type KnownReport = fn(&(dyn StdError + 'static)) -> Option<DiagnosticReport>;
+
+/// Capture a report as it is.
+fn get_known_report(error: &(dyn StdError + 'static)) -> Option<DiagnosticReport> {
+    error.downcast_ref::<DiagnosticReport>().cloned()
+}
+
+/// Capture a report, or a failure or aggregate with the action type `T`.
+pub(crate) fn get_known_failure<T: Action + 'static>(
+    error: &(dyn StdError + 'static),
+) -> Option<DiagnosticReport> {
+    if let Some(failure) = error.downcast_ref::<Failure<T>>() {
+        return Some(failure.to_report());
+    }
+    if let Some(failures) = error.downcast_ref::<Failures<T>>() {
+        return Some(failures.to_report());
+    }
+    get_known_report(error)
+}
+
+impl Display for DiagnosticReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}{}", self.message, format_context(&self.context))
+    }
+}
+
+impl StdError for DiagnosticReport {
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from struct reference to trait object"
+    )]
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_deref()
+            .map(|cause| cause as &(dyn StdError + 'static))
+    }
+}
+
+impl Diagnostic for DiagnosticReport {
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from boxed struct to trait object"
+    )]
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.code.as_ref().map(|code| Box::new(code) as _)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from boxed struct to trait object"
+    )]
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help.as_ref().map(|help| Box::new(help) as _)
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from boxed struct to trait object"
+    )]
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.url.as_ref().map(|url| Box::new(url) as _)
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from struct reference to trait object"
+    )]
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.source_code
+            .as_ref()
+            .map(|source| source as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            None
+        } else {
+            Some(Box::new(self.labels.iter().cloned()))
+        }
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from struct reference to trait object"
+    )]
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.related.is_empty() {
+            None
+        } else {
+            Some(Box::new(
+                self.related.iter().map(|report| report as &dyn Diagnostic),
+            ))
+        }
+    }
+}
+
+impl DiagnosticExt for DiagnosticReport {}
+
+impl ReportSource {
+    /// Read the full text and name of source code.
+    fn read(source: &dyn SourceCode) -> Option<Self> {
+        let contents = source
+            .read_span(&SourceSpan::from((0, 0)), 0, usize::MAX)
+            .ok()?;
+        Some(Self {
+            name: contents.name().map(ToOwned::to_owned),
+            text: String::from_utf8_lossy(contents.data()).into_owned(),
+        })
+    }
+}
+
+impl SourceCode for ReportSource {
+    fn read_span<'a>(
+        &'a self,
+        span: &SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        let contents = self
+            .text
+            .read_span(span, context_lines_before, context_lines_after)?;
+        let span = *contents.span();
+        let line = contents.line();
+        let column = contents.column();
+        let line_count = contents.line_count();
+        let data = contents.data();
+        Ok(Box::new(match &self.name {
+            Some(name) => {
+                MietteSpanContents::new_named(name.clone(), data, span, line, column, line_count)
+            }
+            None => MietteSpanContents::new(data, span, line, column, line_count),
+        }))
+    }
+}
+
+impl Display for Cause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}{}", self.message, format_context(&self.context))
+    }
+}
+
+impl StdError for Cause {
+    #[expect(
+        clippy::as_conversions,
+        reason = "cast from struct reference to trait object"
+    )]
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_deref()
+            .map(|cause| cause as &(dyn StdError + 'static))
+    }
+}
+
+/// Capture an error and its sources, with the context of known types.
+fn get_cause(error: Option<&(dyn StdError + 'static)>, known: KnownReport) -> Option<Box<Cause>> {
+    error.map(|error| {
+        let (message, context) = match known(error) {
+            Some(report) => (report.message, report.context),
+            None => (strip_ansi(&error.to_string()), Vec::new()),
+        };
+        Box::new(Cause {
+            message,
+            context,
+            source: get_cause(error.source(), known),
+        })
+    })
+}
+
+/// Remove ANSI escape sequences such as colors and hyperlinks.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            // Control sequence, ending with a byte in `@` to `~`
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // Operating system command, ending with BEL or ST
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    output
+}
+
+/// Serialized form of a [`Cause`] without its source.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum CauseEntry {
+    Message(String),
+    WithContext {
+        message: String,
+        #[serde(with = "context_map")]
+        context: Vec<(String, String)>,
+    },
+}
+
+#[expect(
+    clippy::ref_option,
+    reason = "signature required by serde serialize_with"
+)]
+fn serialize_causes<S: Serializer>(
+    source: &Option<Box<Cause>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries = Vec::new();
+    let mut current = source.as_deref();
+    while let Some(cause) = current {
+        entries.push(if cause.context.is_empty() {
+            CauseEntry::Message(cause.message.clone())
+        } else {
+            CauseEntry::WithContext {
+                message: cause.message.clone(),
+                context: cause.context.clone(),
+            }
+        });
+        current = cause.source.as_deref();
+    }
+    entries.serialize(serializer)
+}
+
+fn deserialize_causes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Box<Cause>>, D::Error> {
+    let entries = Vec::<CauseEntry>::deserialize(deserializer)?;
+    Ok(entries.into_iter().rev().fold(None, |source, entry| {
+        let (message, context) = match entry {
+            CauseEntry::Message(message) => (message, Vec::new()),
+            CauseEntry::WithContext { message, context } => (message, context),
+        };
+        Some(Box::new(Cause {
+            message,
+            context,
+            source,
+        }))
+    }))
+}
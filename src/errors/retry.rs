@@ -0,0 +1,45 @@
+//! Classification of transient errors.
+
+use std::error::Error as StdError;
+use std::io::{Error as IoError, ErrorKind};
+
+/// Whether an [`ErrorKind`] is typically transient and worth retrying.
+#[must_use]
+pub fn is_transient_io_kind(kind: ErrorKind) -> bool {
+    matches!(
+        kind,
+        ErrorKind::TimedOut
+            | ErrorKind::Interrupted
+            | ErrorKind::WouldBlock
+            | ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::BrokenPipe
+    )
+}
+
+/// Whether an HTTP status code is typically transient and worth retrying.
+///
+/// Returns `None` for status codes that are not errors.
+#[must_use]
+pub fn is_transient_status_code(status_code: u16) -> Option<bool> {
+    match status_code {
+        408 | 425 | 429 | 500 | 502..=504 => Some(true),
+        400..=599 => Some(false),
+        _ => None,
+    }
+}
+
+/// Infer whether the first [`IoError`] in an error or its source chain is transient.
+#[must_use]
+pub fn is_transient_source(error: &(dyn StdError + 'static)) -> Option<bool> {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if let Some(io_error) = error.downcast_ref::<IoError>() {
+            return Some(is_transient_io_kind(io_error.kind()));
+        }
+        current = error.source();
+    }
+    None
+}
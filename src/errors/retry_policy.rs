@@ -0,0 +1,75 @@
+//! Helper to retry operations that fail with a retryable [`Failure`].
+
+use super::{Action, Failure};
+use colored::Colorize;
+use log::warn;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Policy controlling how many times and how often to retry.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first.
+    ///
+    /// Default: `3`
+    pub max_attempts: u32,
+
+    /// Delay before the first retry.
+    ///
+    /// Default: `1s`
+    pub delay: Duration,
+
+    /// Factor to multiply the delay by after each retry.
+    ///
+    /// Default: `2`
+    pub multiplier: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            delay: Duration::from_secs(1),
+            multiplier: 2,
+        }
+    }
+}
+
+/// Run the operation, retrying while it fails with a retryable [`Failure`].
+///
+/// Each retry is logged at the warn level. The delay is taken from
+/// [`Failure::retry_after`] if set, otherwise from the policy.
+///
+/// The final failure has the number of attempts added as `attempts` context.
+///
+/// # Example
+///
+/// ```text
+/// let users = retry(&RetryPolicy::default(), |_attempt| fetch_users(&client))?;
+/// ```
+pub fn retry<R, T: Action>(
+    policy: &RetryPolicy,
+    mut operation: impl FnMut(u32) -> Result<R, Failure<T>>,
+) -> Result<R, Failure<T>> {
+    let mut attempt = 1;
+    let mut delay = policy.delay;
+    loop {
+        match operation(attempt) {
+            Ok(value) => return Ok(value),
+            Err(failure) if failure.is_retryable() && attempt < policy.max_attempts => {
+                let wait = failure.retry_after().unwrap_or(delay);
+                warn!(
+                    "{} attempt {attempt} of {} to {}, retrying in {:.3}s",
+                    "Failed".bold(),
+                    policy.max_attempts,
+                    failure.action(),
+                    wait.as_secs_f64()
+                );
+                sleep(wait);
+                delay = delay.saturating_mul(policy.multiplier);
+                attempt += 1;
+            }
+            Err(failure) => return Err(failure.set("attempts", attempt.to_string())),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Locating parse errors in their original input.
+
+use super::{Action, Failure};
+use miette::SourceSpan;
+use std::error::Error as StdError;
+#[cfg(feature = "toml")]
+use toml::de::Error as TomlError;
+
+/// A parse error that knows where in its input it occurred.
+///
+/// Implemented for `serde_json::Error`, `serde_yaml::Error` and
+/// `toml::de::Error` with the feature of the same name.
+///
+/// For other parsers use [`Failure::with_source_location`] with the line and
+/// column they report.
+pub trait SourceLocation: StdError {
+    /// One-based line and column of the error, if known.
+    ///
+    /// Default: `None`
+    fn location(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Span of the error in the input, if known.
+    ///
+    /// Default: one character at [`SourceLocation::location`]
+    fn span(&self, text: &str) -> Option<SourceSpan> {
+        self.location()
+            .map(|(line, column)| get_span(text, line, column))
+    }
+
+    /// Message displayed on the label.
+    ///
+    /// Default: the error's `Display` output
+    fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl SourceLocation for serde_json::Error {
+    fn location(&self) -> Option<(usize, usize)> {
+        (self.line() > 0).then(|| (self.line(), self.column()))
+    }
+
+    fn label(&self) -> String {
+        let message = self.to_string();
+        let suffix = format!(" at line {} column {}", self.line(), self.column());
+        message
+            .strip_suffix(&suffix)
+            .map_or_else(|| message.clone(), ToOwned::to_owned)
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+impl SourceLocation for serde_yaml::Error {
+    fn location(&self) -> Option<(usize, usize)> {
+        serde_yaml::Error::location(self).map(|location| (location.line(), location.column()))
+    }
+
+    /// The column is counted in characters rather than bytes.
+    fn span(&self, text: &str) -> Option<SourceSpan> {
+        SourceLocation::location(self).map(|(line, column)| get_char_span(text, line, column))
+    }
+
+    fn label(&self) -> String {
+        let message = self.to_string();
+        let Some((line, column)) = SourceLocation::location(self) else {
+            return message;
+        };
+        let suffix = format!(" at line {line} column {column}");
+        message
+            .strip_suffix(&suffix)
+            .map_or_else(|| message.clone(), ToOwned::to_owned)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl SourceLocation for TomlError {
+    fn span(&self, _text: &str) -> Option<SourceSpan> {
+        TomlError::span(self).map(SourceSpan::from)
+    }
+
+    fn label(&self) -> String {
+        self.message().to_owned()
+    }
+}
+
+impl<T: Action> Failure<T> {
+    /// Returns a closure for use with `map_err` that points a label at the
+    /// location of the parse error in the input.
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// let config: Config = serde_yaml::from_str(&text)
+    ///     .map_err(Failure::wrap_with_source(Action::ParseConfig, path, &text))?;
+    /// ```
+    pub fn wrap_with_source<E>(
+        action: T,
+        name: impl AsRef<str>,
+        text: impl Into<String>,
+    ) -> impl FnOnce(E) -> Self
+    where
+        E: SourceLocation + Send + Sync + 'static,
+    {
+        let name = name.as_ref().to_owned();
+        let text = text.into();
+        move |e| {
+            let span = e.span(&text);
+            let label = e.label();
+            let failure = Self::new(action, e).with_source_code(name, text);
+            match span {
+                Some(span) => failure.with_primary_label(span, label),
+                None => failure,
+            }
+        }
+    }
+
+    /// Set the source code and add a primary label at a one-based line and column.
+    ///
+    /// A column of `0` points at the start of the line.
+    #[must_use]
+    pub fn with_source_location(
+        self,
+        name: impl AsRef<str>,
+        text: impl Into<String>,
+        line: usize,
+        column: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let text = text.into();
+        let span = get_span(&text, line, column);
+        self.with_source_code(name, text)
+            .with_primary_label(span, message)
+    }
+}
+
+/// Convert a one-based line and byte column to a span of one character.
+///
+/// Locations past the end of a line or the input are clamped.
+pub(crate) fn get_span(text: &str, line: usize, column: usize) -> SourceSpan {
+    let (offset, remaining) = get_line(text, line);
+    let column_offset = column
+        .saturating_sub(1)
+        .min(remaining.trim_end_matches('\n').len());
+    get_char_at(text, offset + column_offset)
+}
+
+/// Convert a one-based line and character column to a span of one character.
+///
+/// Locations past the end of a line or the input are clamped.
+#[cfg(feature = "serde_yaml")]
+fn get_char_span(text: &str, line: usize, column: usize) -> SourceSpan {
+    let (offset, remaining) = get_line(text, line);
+    let remaining = remaining.trim_end_matches('\n');
+    let column_offset = remaining
+        .char_indices()
+        .nth(column.saturating_sub(1))
+        .map_or(remaining.len(), |(index, _)| index);
+    get_char_at(text, offset + column_offset)
+}
+
+/// Byte offset and content of a one-based line, or the end of the input.
+fn get_line(text: &str, line: usize) -> (usize, &str) {
+    let mut offset = 0;
+    for (index, current) in text.split_inclusive('\n').enumerate() {
+        if index + 1 == line {
+            return (offset, current);
+        }
+        offset += current.len();
+    }
+    (offset, "")
+}
+
+/// Span of the character at a byte offset, or an empty span at a line end.
+fn get_char_at(text: &str, offset: usize) -> SourceSpan {
+    let length = text
+        .get(offset..)
+        .and_then(|rest| rest.chars().next())
+        .filter(|c| *c != '\n')
+        .map_or(0, char::len_utf8);
+    SourceSpan::new(offset.into(), length)
+}
@@ -0,0 +1,75 @@
+use crate::{get_message_catalog, EnglishCatalog, Error, MessageCatalog};
+
+struct GermanCatalog;
+
+impl MessageCatalog for GermanCatalog {
+    fn failed(&self) -> String {
+        "Fehler".to_owned()
+    }
+
+    fn failed_to(&self, failed: &str, action: &str) -> String {
+        format!("{failed}: {action}")
+    }
+
+    fn domain_error(&self, domain: &str) -> String {
+        format!("Ein {domain}-Fehler ist aufgetreten")
+    }
+
+    fn caused_by(&self, cause: &str) -> String {
+        format!("Verursacht durch: {cause}")
+    }
+}
+
+#[test]
+fn english_catalog_defaults() {
+    // Arrange
+    let catalog = EnglishCatalog;
+
+    // Act & Assert
+    assert_eq!(
+        catalog.failed_to(&catalog.failed(), "load config"),
+        "Failed to load config"
+    );
+    assert_eq!(catalog.domain_error("io"), "A io error occurred");
+    assert_eq!(catalog.status_code_error(404), "A 404 error occurred");
+    assert_eq!(
+        catalog.caused_by("file not found"),
+        "Caused by: file not found"
+    );
+}
+
+#[test]
+fn get_message_catalog_defaults_to_english() {
+    // Arrange
+    let catalog = get_message_catalog();
+
+    // Act
+    let failed = catalog.failed();
+
+    // Assert
+    assert_eq!(failed, "Failed");
+}
+
+#[test]
+fn display_with_uses_catalog() {
+    // Arrange
+    let error = Error {
+        action: "Konfiguration laden".to_owned(),
+        message: "Datei nicht gefunden".to_owned(),
+        domain: Some("io".to_owned()),
+        status_code: Some(404),
+        causes: vec!["No such file or directory".to_owned()],
+        ..Error::default()
+    };
+
+    // Act
+    let display = error.display_with(&GermanCatalog);
+
+    // Assert
+    assert!(display.contains(": Konfiguration laden"));
+    assert!(display.contains("Fehler"));
+    assert!(display.contains("Ein io-Fehler ist aufgetreten"));
+    assert!(display.contains("A 404 error occurred"));
+    assert!(display.contains("Datei nicht gefunden"));
+    assert!(display.contains("  Verursacht durch: No such file or directory"));
+}
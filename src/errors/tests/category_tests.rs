@@ -0,0 +1,90 @@
+use crate::errors::tests::test_helpers::io_error;
+use crate::{Error, ErrorCategory, ToError};
+use std::io::{Error as IoError, ErrorKind};
+
+#[test]
+fn exit_code_follows_sysexits() {
+    // Arrange & Act & Assert
+    assert_eq!(ErrorCategory::Usage.exit_code(), 64);
+    assert_eq!(ErrorCategory::NotFound.exit_code(), 66);
+    assert_eq!(ErrorCategory::Network.exit_code(), 69);
+    assert_eq!(ErrorCategory::Internal.exit_code(), 70);
+    assert_eq!(ErrorCategory::Io.exit_code(), 74);
+    assert_eq!(ErrorCategory::Permission.exit_code(), 77);
+    assert_eq!(ErrorCategory::Config.exit_code(), 78);
+}
+
+#[test]
+fn from_status_code_infers_category() {
+    // Arrange & Act & Assert
+    assert_eq!(
+        ErrorCategory::from_status_code(400),
+        Some(ErrorCategory::Usage)
+    );
+    assert_eq!(
+        ErrorCategory::from_status_code(403),
+        Some(ErrorCategory::Permission)
+    );
+    assert_eq!(
+        ErrorCategory::from_status_code(404),
+        Some(ErrorCategory::NotFound)
+    );
+    assert_eq!(
+        ErrorCategory::from_status_code(503),
+        Some(ErrorCategory::Network)
+    );
+    assert_eq!(ErrorCategory::from_status_code(500), None);
+}
+
+#[test]
+fn from_source_finds_io_error_in_chain() {
+    // Arrange
+    let source = IoError::new(ErrorKind::PermissionDenied, "permission denied");
+
+    // Act
+    let category = ErrorCategory::from_source(&source);
+
+    // Assert
+    assert_eq!(category, Some(ErrorCategory::Permission));
+}
+
+#[test]
+fn error_get_category_prefers_explicit() {
+    // Arrange
+    let error = Error {
+        status_code: Some(404),
+        category: Some(ErrorCategory::Config),
+        ..Error::default()
+    };
+
+    // Act & Assert
+    assert_eq!(error.get_category(), ErrorCategory::Config);
+}
+
+#[test]
+fn error_get_category_infers_from_status_code_then_domain() {
+    // Arrange
+    let not_found = Error {
+        status_code: Some(404),
+        domain: Some("io".to_owned()),
+        ..Error::default()
+    };
+    let io = Error {
+        domain: Some("io".to_owned()),
+        ..Error::default()
+    };
+
+    // Act & Assert
+    assert_eq!(not_found.get_category(), ErrorCategory::NotFound);
+    assert_eq!(io.get_category(), ErrorCategory::Io);
+    assert_eq!(Error::default().get_category(), ErrorCategory::Internal);
+}
+
+#[test]
+fn io_error_to_error_sets_category() {
+    // Arrange & Act
+    let error = io_error().to_error("read config");
+
+    // Assert
+    assert_eq!(error.category, Some(ErrorCategory::NotFound));
+}
@@ -0,0 +1,122 @@
+use crate::errors::tests::test_helpers::*;
+use crate::{Action, Failure, Severity};
+use insta::assert_snapshot;
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Action, Debug, Error)]
+enum ConfigAction {
+    #[error("read config")]
+    #[failure(
+        code = "E0001",
+        help = "Check the file exists and is readable",
+        url = "https://docs.example.com/errors/E0001"
+    )]
+    Read,
+    #[error("parse config")]
+    #[failure(code = "E0002", severity = "warning")]
+    Parse { path: String },
+    #[error("validate config")]
+    Validate(String),
+    #[error("fetch config")]
+    #[failure(domain = "remote config", status_code = 503)]
+    Fetch,
+}
+
+#[derive(Action, Debug, Error)]
+#[error("sync")]
+#[failure(code = "E0100", severity = "advice")]
+struct SyncAction;
+
+#[derive(Action, Debug, Error)]
+enum PlainAction {
+    #[error("clean cache")]
+    Clean,
+}
+
+#[test]
+fn derive_action_provides_variant_defaults() {
+    let failure = Failure::new(ConfigAction::Read, io_error());
+    assert_eq!(
+        failure.code().map(|c| c.to_string()).as_deref(),
+        Some("E0001")
+    );
+    assert_eq!(
+        failure.help().map(|h| h.to_string()).as_deref(),
+        Some("Check the file exists and is readable")
+    );
+    assert_eq!(
+        failure.url().map(|u| u.to_string()).as_deref(),
+        Some("https://docs.example.com/errors/E0001")
+    );
+    assert_eq!(failure.severity(), None);
+}
+
+#[test]
+fn derive_action_matches_struct_variants() {
+    let action = ConfigAction::Parse {
+        path: "/etc/myapp/config.yaml".to_owned(),
+    };
+    assert_eq!(action.code().as_deref(), Some("E0002"));
+    assert_eq!(action.severity(), Some(Severity::Warning));
+    assert_eq!(action.help(), None);
+}
+
+#[test]
+fn derive_action_without_attribute_falls_back_to_short_code() {
+    let failure = Failure::from_action(ConfigAction::Validate("port".to_owned()));
+    assert_eq!(
+        failure.code().map(|c| c.to_string()).as_deref(),
+        Some("rogue_logging::ConfigAction::Validate")
+    );
+}
+
+#[test]
+fn derive_action_provides_domain_and_status_code() {
+    let error = Failure::from_action(ConfigAction::Fetch).to_error();
+    assert_eq!(error.domain.as_deref(), Some("remote config"));
+    assert_eq!(error.status_code, Some(503));
+    assert_eq!(ConfigAction::Read.status_code(), None);
+}
+
+#[test]
+fn derive_action_without_any_attributes() {
+    let failure = Failure::from_action(PlainAction::Clean);
+    assert_eq!(
+        failure.code().map(|c| c.to_string()).as_deref(),
+        Some("rogue_logging::PlainAction::Clean")
+    );
+    assert!(failure.help().is_none());
+    assert!(failure.url().is_none());
+    assert_eq!(failure.severity(), None);
+}
+
+#[test]
+fn derive_action_on_struct() {
+    assert_eq!(SyncAction.code().as_deref(), Some("E0100"));
+    assert_eq!(SyncAction.severity(), Some(Severity::Advice));
+}
+
+#[test]
+fn builder_overrides_derived_defaults() {
+    let failure = Failure::new(ConfigAction::Read, io_error())
+        .with_code("custom")
+        .with_help("Custom help")
+        .with_severity(Severity::Error);
+    assert_eq!(
+        failure.code().map(|c| c.to_string()).as_deref(),
+        Some("custom")
+    );
+    assert_eq!(
+        failure.help().map(|h| h.to_string()).as_deref(),
+        Some("Custom help")
+    );
+    assert_eq!(failure.severity(), Some(Severity::Error));
+}
+
+#[test]
+fn miette_render_with_derived_defaults() {
+    use_colors(false);
+    let failure = Failure::new(ConfigAction::Read, io_error());
+    assert_snapshot!(render_diagnostic(&failure));
+}
@@ -1,6 +1,17 @@
 use crate::errors::diagnostic_ext::DiagnosticExt;
+#[cfg(feature = "log")]
+use crate::errors::diagnostic_ext::{get_level, get_log_lines};
 use crate::errors::tests::test_helpers::*;
-use crate::Failure;
+use crate::{Failure, RenderMode, RenderOptions, RenderTheme};
+#[cfg(feature = "log")]
+use crate::{Failures, Severity};
+use clap::{Parser, ValueEnum};
+use insta::assert_snapshot;
+#[cfg(feature = "log")]
+use log::Level;
+use miette::Diagnostic;
+use serde_json::Value;
+use thiserror::Error;
 
 #[test]
 fn render_produces_output() {
@@ -19,3 +30,195 @@ fn render_includes_nested_cause() {
     assert!(rendered.contains("cache users"));
     assert!(rendered.contains("parse response"));
 }
+
+#[test]
+fn render_narratable_snapshot() {
+    use_colors(false);
+    let failure = http_error().with_help("Check the API is reachable");
+    assert_snapshot!(failure.render_narratable());
+}
+
+#[test]
+fn render_plain_snapshot() {
+    use_colors(false);
+    let failure = http_error();
+    assert_snapshot!(failure.render_with(RenderMode::Plain));
+}
+
+#[test]
+fn render_json_is_machine_readable() {
+    use_colors(false);
+    let failure = Failure::new(TestAction::ReadConfig, io_error())
+        .with_code("config::read")
+        .with_help("Check the file exists");
+    let json: Value = serde_json::from_str(&failure.render_json()).expect("should be valid JSON");
+    assert_eq!(
+        json.pointer("/message").and_then(Value::as_str),
+        Some("Failed to read config")
+    );
+    assert_eq!(
+        json.pointer("/code").and_then(Value::as_str),
+        Some("config::read")
+    );
+    assert_eq!(
+        json.pointer("/help").and_then(Value::as_str),
+        Some("Check the file exists")
+    );
+    assert_eq!(
+        json.pointer("/causes/0").and_then(Value::as_str),
+        Some("file not found")
+    );
+}
+
+#[test]
+fn render_with_selects_handler() {
+    use_colors(false);
+    let failure = Failure::new(TestAction::ReadConfig, io_error());
+    assert_eq!(failure.render_with(RenderMode::Graphical), failure.render());
+    assert_eq!(
+        failure.render_with(RenderMode::Narratable),
+        failure.render_narratable()
+    );
+    assert_eq!(failure.render_with(RenderMode::Json), failure.render_json());
+}
+
+#[test]
+fn render_mode_serde_and_value_enum() {
+    let json = serde_json::to_string(&RenderMode::Narratable).expect("should serialize");
+    assert_eq!(json, "\"narratable\"");
+    let mode = RenderMode::from_str("json", true).expect("should parse");
+    assert_eq!(mode, RenderMode::Json);
+    assert_eq!(RenderMode::default(), RenderMode::Graphical);
+}
+
+#[test]
+#[cfg(feature = "miette-fancy")]
+fn render_with_options_snapshot() {
+    use_colors(false);
+    let config = "server:\n  host: localhost\n  port: eighty\n";
+    let failure = Failure::from_action(TestAction::ParseConfigFile)
+        .with_source_code("/etc/myapp/config.yaml", config)
+        .with_primary_label((34, 6), "expected an integer")
+        .with_url("https://docs.example.com/config");
+    let options = RenderOptions {
+        render_theme: Some(RenderTheme::Ascii),
+        render_context_lines: Some(2),
+        render_footer: Some("Run with --help for usage".to_owned()),
+        render_links: Some(false),
+        ..RenderOptions::default()
+    };
+    assert_snapshot!(failure.render_with_options(&options));
+}
+
+#[test]
+#[cfg(feature = "miette-fancy")]
+fn render_without_colors_omits_hyperlinks_by_default() {
+    use_colors(false);
+    let failure =
+        Failure::from_action(TestAction::ReadConfig).with_url("https://docs.example.com/config");
+    let rendered = failure.render_with_options(&RenderOptions::default());
+    assert!(!rendered.contains("\x1b]8;;"));
+    assert!(rendered.contains("https://docs.example.com/config"));
+}
+
+#[test]
+fn render_with_options_uses_mode() {
+    use_colors(false);
+    let failure = Failure::new(TestAction::ReadConfig, io_error());
+    let options = RenderOptions {
+        render_mode: Some(RenderMode::Json),
+        ..RenderOptions::default()
+    };
+    assert_eq!(failure.render_with_options(&options), failure.render_json());
+}
+
+#[test]
+fn render_options_parse_from_args() {
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        render: RenderOptions,
+    }
+    let cli = Cli::parse_from([
+        "app",
+        "--render-mode",
+        "narratable",
+        "--render-theme",
+        "ascii",
+        "--render-width",
+        "100",
+    ]);
+    assert_eq!(cli.render.render_mode, Some(RenderMode::Narratable));
+    assert_eq!(cli.render.render_theme, Some(RenderTheme::Ascii));
+    assert_eq!(cli.render.render_width, Some(100));
+    assert_eq!(cli.render.render_footer, None);
+}
+
+#[test]
+fn render_options_deserialize() {
+    let json = r#"{"render_mode": "plain", "render_tab_width": 2}"#;
+    let options: RenderOptions = serde_json::from_str(json).expect("should deserialize");
+    assert_eq!(options.render_mode, Some(RenderMode::Plain));
+    assert_eq!(options.render_tab_width, Some(2));
+    assert_eq!(options.render_theme, None);
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn get_level_maps_severity() {
+    assert_eq!(get_level(None), Level::Error);
+    assert_eq!(get_level(Some(Severity::Error)), Level::Error);
+    assert_eq!(get_level(Some(Severity::Warning)), Level::Warn);
+    assert_eq!(get_level(Some(Severity::Advice)), Level::Info);
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn get_log_lines_includes_source_chain_and_related() {
+    use_colors(false);
+    let mut failures = Failures::new(TestAction::ValidateConfig);
+    failures.push(Failure::new(TestAction::ReadConfig, io_error()));
+    failures.push(Failure::new(TestAction::WriteFile, io_error()).with_path("/tmp/out"));
+    assert_eq!(
+        get_log_lines(&failures),
+        vec![
+            "Failed to validate config",
+            "  Failed to read config",
+            "    Caused by: file not found",
+            "  Failed to write file",
+            "  ▷ path: /tmp/out",
+            "    Caused by: file not found"
+        ]
+    );
+    assert_snapshot!(get_log_lines(&http_error()).join("\n"));
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn log_failures_logs_each_child() {
+    use_colors(false);
+    let mut failures = Failures::new(TestAction::ValidateConfig);
+    failures.push(Failure::new(TestAction::ReadConfig, io_error()));
+    let records = capture_logs(|| DiagnosticExt::log(&failures));
+    assert_eq!(
+        records,
+        vec![
+            (Level::Error, "Failed to validate config".to_owned()),
+            (Level::Error, "  Failed to read config".to_owned()),
+            (Level::Error, "    Caused by: file not found".to_owned())
+        ]
+    );
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("custom diagnostic")]
+struct CustomDiagnostic;
+
+crate::impl_diagnostic_ext!(CustomDiagnostic);
+
+#[test]
+fn impl_diagnostic_ext_renders_other_diagnostics() {
+    use_colors(false);
+    assert!(CustomDiagnostic.render().contains("custom diagnostic"));
+    assert_eq!(CustomDiagnostic.footer(&RenderOptions::default()), None);
+}
@@ -1,8 +1,10 @@
+use crate::errors::error::get_frames;
 use crate::errors::tests::test_helpers::use_colors;
 use crate::Error;
 #[cfg(feature = "log")]
 use insta::assert_snapshot;
 use insta::assert_yaml_snapshot;
+use std::backtrace::Backtrace;
 
 #[test]
 fn serialize_error() {
@@ -31,6 +33,91 @@ fn serialize_error_with_domain() {
     assert_yaml_snapshot!(error);
 }
 
+#[test]
+fn serialize_error_with_causes() {
+    // Arrange
+    let error = Error {
+        action: "perform action".to_owned(),
+        message: "Something went wrong".to_owned(),
+        causes: vec!["Inner failure".to_owned(), "Root failure".to_owned()],
+        ..Error::default()
+    };
+
+    // Act & Assert
+    assert_yaml_snapshot!(error);
+}
+
+#[test]
+fn serialize_error_with_context() {
+    // Arrange
+    let error = Error {
+        action: "perform action".to_owned(),
+        message: "Something went wrong".to_owned(),
+        context: vec![
+            ("path".to_owned(), "/etc/config.yaml".to_owned()),
+            ("attempt".to_owned(), "3".to_owned()),
+        ],
+        ..Error::default()
+    };
+
+    // Act & Assert
+    assert_yaml_snapshot!(error);
+}
+
+#[test]
+fn deserialize_error_preserves_context_order() {
+    // Arrange
+    let json = r#"{"action":"a","message":"m","context":{"zulu":"1","alpha":"2"}}"#;
+
+    // Act
+    let error: Error = serde_json::from_str(json).expect("should deserialize");
+
+    // Assert
+    assert_eq!(
+        error.context,
+        vec![
+            ("zulu".to_owned(), "1".to_owned()),
+            ("alpha".to_owned(), "2".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn deserialize_error_without_optional_fields() {
+    // Arrange
+    let json = r#"{"action":"perform action","message":"Something went wrong"}"#;
+
+    // Act
+    let error: Error = serde_json::from_str(json).expect("should deserialize");
+
+    // Assert
+    assert!(error.context.is_empty());
+    assert!(error.causes.is_empty());
+    assert!(error.backtrace_frames.is_none());
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn display_includes_causes() {
+    // Arrange
+    use_colors(false);
+    let error = Error {
+        action: "load config".to_owned(),
+        message: "Failed to parse config file".to_owned(),
+        causes: vec![
+            "Failed to read file\n▷ path: /etc/config.yaml".to_owned(),
+            "file not found".to_owned(),
+        ],
+        ..Error::default()
+    };
+
+    // Act
+    let display = error.display();
+
+    // Assert
+    assert_snapshot!(display);
+}
+
 #[test]
 #[cfg(feature = "log")]
 fn display_returns_multiline_string() {
@@ -86,6 +173,83 @@ fn clone_loses_backtrace() {
     assert!(cloned.backtrace.is_none());
 }
 
+#[test]
+fn with_backtrace_frames_survives_serde_and_clone() {
+    // Arrange
+    let error = Error {
+        action: "test".to_owned(),
+        message: "test".to_owned(),
+        backtrace: Some(Backtrace::force_capture()),
+        ..Error::default()
+    };
+
+    // Act
+    let error = error.with_backtrace_frames();
+    let json = serde_json::to_string(&error).expect("should serialize");
+    let deserialized: Error = serde_json::from_str(&json).expect("should deserialize");
+    let cloned = deserialized.clone();
+
+    // Assert
+    let frames = error.backtrace_frames.expect("frames should be set");
+    assert!(!frames.is_empty());
+    assert_eq!(cloned.backtrace_frames, Some(frames));
+}
+
+#[test]
+fn with_backtrace_frames_without_backtrace() {
+    // Arrange
+    let error = Error {
+        backtrace: None,
+        ..Error::default()
+    };
+
+    // Act
+    let error = error.with_backtrace_frames();
+
+    // Assert
+    assert!(error.backtrace_frames.is_none());
+}
+
+#[test]
+fn get_frames_joins_symbol_and_location() {
+    // Arrange
+    let backtrace = "   0: my_crate::load_config
+             at ./src/config.rs:42:5
+   1: std::rt::lang_start
+   2: main
+note: Some details are omitted";
+
+    // Act
+    let frames = get_frames(backtrace);
+
+    // Assert
+    assert_eq!(
+        frames,
+        vec![
+            "my_crate::load_config at ./src/config.rs:42:5".to_owned(),
+            "std::rt::lang_start".to_owned(),
+            "main".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn clone_keeps_causes() {
+    // Arrange
+    let error = Error {
+        action: "test".to_owned(),
+        message: "test".to_owned(),
+        causes: vec!["cause".to_owned()],
+        ..Error::default()
+    };
+
+    // Act
+    let cloned = error.clone();
+
+    // Assert
+    assert_eq!(cloned.causes, error.causes);
+}
+
 #[test]
 fn debug_matches_display() {
     // Arrange
@@ -114,4 +278,7 @@ fn default_has_empty_strings() {
     assert!(error.message.is_empty());
     assert!(error.domain.is_none());
     assert!(error.status_code.is_none());
+    assert!(error.context.is_empty());
+    assert!(error.causes.is_empty());
+    assert!(error.backtrace_frames.is_none());
 }
@@ -1,10 +1,58 @@
 use crate::errors::tests::test_helpers::*;
-use crate::{Failure, Severity};
+#[cfg(feature = "miette-fancy")]
+use crate::RenderOptions;
+use crate::{Action, DiagnosticExt, ErrorCategory, Failure, RenderMode, Severity};
 use insta::assert_snapshot;
-use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, NarratableReportHandler};
+#[cfg(feature = "log")]
+use log::Level;
+use miette::{Diagnostic, LabeledSpan, NarratableReportHandler};
+#[cfg(feature = "miette-fancy")]
+use miette::{GraphicalReportHandler, GraphicalTheme};
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::error::Error as StdError;
 use std::io::Error as IoError;
 use std::io::ErrorKind as IoErrorKind;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+enum ApiAction {
+    #[error("fetch user")]
+    FetchUser,
+    #[error("update user")]
+    UpdateUser,
+}
+
+impl Action for ApiAction {
+    fn code(&self) -> Option<String> {
+        Some("api::user".to_owned())
+    }
+
+    fn help(&self) -> Option<String> {
+        match self {
+            Self::FetchUser => Some("Check the user exists".to_owned()),
+            Self::UpdateUser => None,
+        }
+    }
+
+    fn url(&self) -> Option<String> {
+        Some("https://docs.example.com/api".to_owned())
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Warning)
+    }
+
+    fn domain(&self) -> Option<String> {
+        Some("api".to_owned())
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::FetchUser => Some(404),
+            Self::UpdateUser => Some(503),
+        }
+    }
+}
 
 #[test]
 fn display_shows_action() {
@@ -82,6 +130,22 @@ fn to_error_converts_correctly() {
     assert_eq!(error.domain, Some("configuration".to_owned()));
 }
 
+#[test]
+fn to_error_keeps_context_except_domain() {
+    let failure = Failure::new(TestAction::LoadConfig, io_error())
+        .with("domain", "configuration")
+        .with_path("/etc/config.yaml")
+        .with("format", "yaml");
+    let error = failure.to_error();
+    assert_eq!(
+        error.context,
+        vec![
+            ("path".to_owned(), "/etc/config.yaml".to_owned()),
+            ("format".to_owned(), "yaml".to_owned()),
+        ]
+    );
+}
+
 #[test]
 fn to_error_uses_type_name_when_no_domain() {
     let failure = Failure::new(TestAction::ReadConfig, io_error());
@@ -166,14 +230,107 @@ fn to_error_snapshot() {
     assert_snapshot!(error.display());
 }
 
-fn render_diagnostic(diagnostic: &dyn Diagnostic) -> String {
-    let mut output = String::new();
-    let handler =
-        GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor()).with_links(false);
-    handler
-        .render_report(&mut output, diagnostic)
-        .expect("should render diagnostic");
-    output
+#[test]
+fn to_error_collects_causes() {
+    use_colors(false);
+    let failure = Failure::new(TestAction::GetAllUsers, http_error());
+    let error = failure.to_error();
+    assert_eq!(error.causes.len(), 2);
+    assert_snapshot!(error.display());
+}
+
+#[test]
+fn to_error_without_source_has_no_causes() {
+    let failure = Failure::from_action(TestAction::GetAllUsers);
+    let error = failure.to_error();
+    assert!(error.causes.is_empty());
+}
+
+#[test]
+fn get_category_infers_from_nested_io_error() {
+    let failure = Failure::new(TestAction::GetAllUsers, http_error());
+    assert_eq!(failure.get_category(), ErrorCategory::Io);
+}
+
+#[test]
+fn get_category_prefers_explicit() {
+    let failure =
+        Failure::new(TestAction::ReadConfig, io_error()).with_category(ErrorCategory::Config);
+    assert_eq!(failure.get_category(), ErrorCategory::Config);
+    assert_eq!(failure.to_error().category, Some(ErrorCategory::Config));
+}
+
+#[test]
+fn get_category_defaults_to_internal() {
+    let failure = Failure::from_action(TestAction::ReadConfig);
+    assert_eq!(failure.get_category(), ErrorCategory::Internal);
+}
+
+#[test]
+fn to_problem_uses_url_and_code() {
+    let failure = Failure::new(TestAction::Authenticate, io_error())
+        .with_url("https://docs.example.com/auth")
+        .with_code("auth::failed");
+    let problem = failure.to_problem();
+    assert_eq!(problem.problem_type, "https://docs.example.com/auth");
+    assert_eq!(problem.code, Some("auth::failed".to_owned()));
+    assert_eq!(problem.title, Some("Failed to authenticate".to_owned()));
+    assert_eq!(problem.detail, Some("file not found".to_owned()));
+}
+
+#[test]
+fn action_defaults_are_used_by_diagnostic() {
+    let failure = Failure::from_action(ApiAction::FetchUser);
+    assert_eq!(
+        failure.code().map(|c| c.to_string()).as_deref(),
+        Some("api::user")
+    );
+    assert_eq!(
+        failure.help().map(|h| h.to_string()).as_deref(),
+        Some("Check the user exists")
+    );
+    assert_eq!(
+        failure.url().map(|u| u.to_string()).as_deref(),
+        Some("https://docs.example.com/api")
+    );
+    assert_eq!(failure.severity(), Some(Severity::Warning));
+}
+
+#[test]
+fn action_defaults_are_used_by_to_error() {
+    let error = Failure::from_action(ApiAction::FetchUser).to_error();
+    assert_eq!(error.domain.as_deref(), Some("api"));
+    assert_eq!(error.status_code, Some(404));
+    assert_eq!(error.category, Some(ErrorCategory::NotFound));
+    assert_eq!(error.retryable, Some(false));
+}
+
+#[test]
+fn action_status_code_infers_retryable() {
+    let failure = Failure::from_action(ApiAction::UpdateUser);
+    assert!(failure.is_retryable());
+    assert_eq!(failure.get_category(), ErrorCategory::Network);
+}
+
+#[test]
+fn builder_overrides_action_defaults() {
+    let failure = Failure::from_action(ApiAction::FetchUser)
+        .with_help("Custom help")
+        .with_severity(Severity::Error)
+        .with("domain", "users");
+    assert_eq!(
+        failure.help().map(|h| h.to_string()).as_deref(),
+        Some("Custom help")
+    );
+    assert_eq!(failure.severity(), Some(Severity::Error));
+    assert_eq!(failure.to_error().domain.as_deref(), Some("users"));
+}
+
+#[test]
+fn to_problem_uses_action_url() {
+    let problem = Failure::from_action(ApiAction::FetchUser).to_problem();
+    assert_eq!(problem.problem_type, "https://docs.example.com/api");
+    assert_eq!(problem.status, Some(404));
 }
 
 #[test]
@@ -208,6 +365,47 @@ fn miette_render_with_context() {
     assert_snapshot!(render_diagnostic(&failure));
 }
 
+#[test]
+fn miette_render_with_source_code() {
+    use_colors(false);
+    let config = "server:\n  host: localhost\n  port: eighty\n";
+    let failure = Failure::from_action(TestAction::ParseConfigFile)
+        .with_source_code("/etc/myapp/config.yaml", config)
+        .with_primary_label((34, 6), "expected an integer")
+        .with_help("Set port to a number between 1 and 65535");
+    assert_snapshot!(render_diagnostic(&failure));
+}
+
+#[test]
+fn miette_render_with_secondary_labels() {
+    use_colors(false);
+    let config = "server:\n  port: 80\nproxy:\n  port: 80\n";
+    let failure = Failure::from_action(TestAction::ValidateConfig)
+        .with_source_code("/etc/myapp/config.yaml", config)
+        .with_label(16..18, "first used here")
+        .with_primary_label(34..36, "port is already in use");
+    assert_snapshot!(render_diagnostic(&failure));
+}
+
+#[test]
+fn labels_are_exposed_through_diagnostic() {
+    let failure = Failure::from_action(TestAction::ParseConfigFile)
+        .with_source_code("config.yaml", "port: eighty")
+        .with_label((0, 4), "key")
+        .with_primary_label((6, 6), "value");
+    let labels: Vec<_> = failure.labels().expect("should have labels").collect();
+    assert_eq!(labels.len(), 2);
+    assert!(labels.iter().any(LabeledSpan::primary));
+    assert!(failure.source_code().is_some());
+}
+
+#[test]
+fn labels_are_none_when_empty() {
+    let failure = Failure::new(TestAction::LoadConfig, io_error());
+    assert!(failure.labels().is_none());
+    assert!(failure.source_code().is_none());
+}
+
 #[test]
 fn miette_render_with_warning_severity() {
     let failure = Failure::new(TestAction::LoadConfig, io_error())
@@ -282,3 +480,139 @@ fn miette_graphical_with_colors() {
     let count = graphical.matches(dimmed_context).count();
     assert_eq!(count, 1);
 }
+
+#[test]
+#[cfg(feature = "log")]
+fn log_lines_snapshot() {
+    use_colors(false);
+    let inner = Failure::new(TestAction::ParseConfigFile, io_error())
+        .with_path("/etc/myapp/config.yaml")
+        .with("line", "42");
+    let failure = Failure::new(TestAction::LoadConfig, inner).with("format", "yaml");
+    assert_snapshot!(failure.log_lines().join("\n"));
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn log_at_severity_level() {
+    use_colors(false);
+    let failure = Failure::new(TestAction::LoadConfig, io_error())
+        .with("format", "yaml")
+        .with_severity(Severity::Warning);
+    let records = capture_logs(|| failure.log());
+    let warnings: Vec<&str> = records
+        .iter()
+        .filter(|(level, _)| *level == Level::Warn)
+        .map(|(_, message)| message.as_str())
+        .collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "Failed to load config",
+            "▷ format: yaml",
+            "  Caused by: file not found"
+        ]
+    );
+    assert!(records
+        .iter()
+        .all(|(level, _)| matches!(level, Level::Warn | Level::Trace)));
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn log_lines_include_related_failures() {
+    use_colors(false);
+    let related = Failure::new(TestAction::ReadConfig, io_error()).with_path("/etc/app.yaml");
+    let failure = Failure::from_action(TestAction::LoadConfig).with_related(related);
+    assert_eq!(
+        failure.log_lines(),
+        vec![
+            "Failed to load config",
+            "  Failed to read config",
+            "  ▷ path: /etc/app.yaml",
+            "    Caused by: file not found"
+        ]
+    );
+}
+
+#[test]
+fn backtrace_is_captured_when_enabled() {
+    let enabled = Backtrace::capture().status() == BacktraceStatus::Captured;
+    let failure = Failure::new(TestAction::LoadConfig, io_error());
+    assert_eq!(failure.backtrace().is_some(), enabled);
+    assert_eq!(failure.to_error().backtrace_frames.is_some(), enabled);
+}
+
+#[test]
+fn to_error_includes_backtrace_frames() {
+    let failure =
+        Failure::from_action(TestAction::LoadConfig).with_backtrace(Backtrace::force_capture());
+    let frames = failure
+        .to_error()
+        .backtrace_frames
+        .expect("should have backtrace frames");
+    assert!(!frames.is_empty());
+}
+
+#[test]
+fn into_error_moves_backtrace() {
+    let failure =
+        Failure::from_action(TestAction::LoadConfig).with_backtrace(Backtrace::force_capture());
+    let error = failure.into_error();
+    assert!(error.backtrace.is_some());
+    assert!(error.backtrace_frames.is_some());
+}
+
+#[test]
+#[cfg(feature = "miette-fancy")]
+fn render_with_options_appends_backtrace_to_footer() {
+    use_colors(false);
+    let failure =
+        Failure::from_action(TestAction::LoadConfig).with_backtrace(Backtrace::force_capture());
+    let options = RenderOptions {
+        render_footer: Some("Run with --help for usage".to_owned()),
+        render_backtrace: Some(true),
+        ..RenderOptions::default()
+    };
+    let rendered = failure.render_with_options(&options);
+    let footer = rendered
+        .find("Run with --help for usage")
+        .expect("should include footer");
+    let backtrace = rendered
+        .find("Backtrace:")
+        .expect("should include backtrace");
+    assert!(footer < backtrace);
+    assert!(rendered.contains("   0: "));
+}
+
+#[test]
+#[cfg(feature = "miette-fancy")]
+fn render_with_options_renders_one_backtrace_for_nested_failures() {
+    use_colors(false);
+    let source =
+        Failure::new(TestAction::ReadConfig, io_error()).with_backtrace(Backtrace::force_capture());
+    let related =
+        Failure::from_action(TestAction::ParseJson).with_backtrace(Backtrace::force_capture());
+    let failure = Failure::new(TestAction::LoadConfig, source)
+        .with_related(related)
+        .with_backtrace(Backtrace::force_capture());
+    let options = RenderOptions {
+        render_backtrace: Some(true),
+        ..RenderOptions::default()
+    };
+    let rendered = failure.render_with_options(&options);
+    assert_eq!(rendered.matches("Backtrace:").count(), 1);
+    assert_eq!(failure.related().map(Iterator::count), Some(1));
+}
+
+#[test]
+fn render_omits_backtrace_by_default() {
+    use_colors(false);
+    let failure =
+        Failure::from_action(TestAction::LoadConfig).with_backtrace(Backtrace::force_capture());
+    assert!(!failure.render().contains("Backtrace:"));
+    assert!(!failure
+        .render_with(RenderMode::Plain)
+        .contains("Backtrace:"));
+    assert!(failure.related().is_none());
+}
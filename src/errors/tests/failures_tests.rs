@@ -0,0 +1,73 @@
+use crate::errors::tests::test_helpers::*;
+use crate::{Error, Failure, Failures};
+use insta::{assert_snapshot, assert_yaml_snapshot};
+use miette::Diagnostic;
+
+fn validation_failures() -> Failures<TestAction> {
+    let mut failures = Failures::new(TestAction::ValidateConfig);
+    failures
+        .push(Failure::new(TestAction::ReadConfig, io_error()).with_path("/etc/myapp/config.yaml"));
+    failures.push_error(Error {
+        action: "parse port".to_owned(),
+        message: "invalid digit found in string".to_owned(),
+        domain: Some("parse".to_owned()),
+        ..Error::default()
+    });
+    failures
+}
+
+#[test]
+fn into_result_is_ok_when_empty() {
+    let failures = Failures::new(TestAction::ValidateConfig);
+    assert!(failures.into_result().is_ok());
+}
+
+#[test]
+fn into_result_is_err_when_not_empty() {
+    let failures = validation_failures();
+    let failures = failures.into_result().expect_err("should be Err");
+    assert_eq!(failures.len(), 2);
+}
+
+#[test]
+fn extend_adds_failures_and_errors() {
+    let mut failures = Failures::new(TestAction::ValidateConfig);
+    failures.extend(vec![
+        Failure::from_action(TestAction::ReadConfig),
+        Failure::from_action(TestAction::WriteFile),
+    ]);
+    failures.extend(vec![Error::default()]);
+    assert_eq!(failures.len(), 3);
+}
+
+#[test]
+fn related_returns_none_when_empty() {
+    let failures = Failures::new(TestAction::ValidateConfig);
+    assert!(failures.related().is_none());
+}
+
+#[test]
+fn serialize_as_list_of_errors() {
+    use_colors(false);
+    let failures = validation_failures();
+    let json = serde_json::to_value(&failures).expect("should serialize");
+    let expected = serde_json::to_value(failures.to_errors()).expect("should serialize");
+    assert_eq!(json, expected);
+    // Backtrace frames depend on `RUST_BACKTRACE`
+    let errors: Vec<Error> = failures
+        .to_errors()
+        .into_iter()
+        .map(|mut error| {
+            error.backtrace_frames = None;
+            error
+        })
+        .collect();
+    assert_yaml_snapshot!("serialize_as_list_of_errors", errors);
+}
+
+#[test]
+fn miette_render_with_children() {
+    use_colors(false);
+    let failures = validation_failures();
+    assert_snapshot!(render_diagnostic(&failures));
+}
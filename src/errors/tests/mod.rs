@@ -1,7 +1,22 @@
-#[cfg(feature = "miette-fancy")]
+mod catalog_tests;
+mod category_tests;
+#[cfg(feature = "derive")]
+mod derive_tests;
+#[cfg(feature = "miette")]
 mod diagnostic_ext_tests;
 mod error_tests;
 #[cfg(feature = "miette")]
 mod failure_tests;
+#[cfg(feature = "miette")]
+mod failures_tests;
+mod problem_tests;
+#[cfg(feature = "miette")]
+mod registry_tests;
+#[cfg(feature = "miette")]
+mod report_tests;
+mod retry_tests;
+#[cfg(feature = "miette")]
+mod source_location_tests;
 #[allow(dead_code)]
 mod test_helpers;
+mod to_error_tests;
@@ -0,0 +1,113 @@
+use crate::{Error, Problem};
+use insta::assert_snapshot;
+
+fn example_error() -> Error {
+    Error {
+        action: "load config".to_owned(),
+        message: "File not found".to_owned(),
+        domain: Some("io".to_owned()),
+        status_code: Some(404),
+        context: vec![
+            ("path".to_owned(), "/etc/config.yaml".to_owned()),
+            ("format".to_owned(), "yaml".to_owned()),
+        ],
+        causes: vec!["No such file or directory".to_owned()],
+        ..Error::default()
+    }
+}
+
+#[test]
+fn from_error_serializes_to_problem_json() {
+    // Arrange
+    let problem = Problem::from(&example_error());
+
+    // Act
+    let json = serde_json::to_string_pretty(&problem).expect("should serialize");
+
+    // Assert
+    assert_snapshot!(json);
+}
+
+#[test]
+fn problem_round_trips_to_error() {
+    // Arrange
+    let error = example_error();
+    let json = serde_json::to_string(&Problem::from(&error)).expect("should serialize");
+
+    // Act
+    let problem: Problem = serde_json::from_str(&json).expect("should deserialize");
+    let parsed = Error::from(problem);
+
+    // Assert
+    assert_eq!(parsed.action, error.action);
+    assert_eq!(parsed.message, error.message);
+    assert_eq!(parsed.domain, error.domain);
+    assert_eq!(parsed.status_code, error.status_code);
+    assert_eq!(parsed.context, error.context);
+    assert_eq!(parsed.causes, error.causes);
+}
+
+#[test]
+fn deserialize_minimal_problem() {
+    // Arrange
+    let json = r#"{"title":"Not Found","status":404}"#;
+
+    // Act
+    let problem: Problem = serde_json::from_str(json).expect("should deserialize");
+    let error = Error::from(problem.clone());
+
+    // Assert
+    assert_eq!(problem.problem_type, "about:blank");
+    assert!(problem.extensions.is_empty());
+    assert_eq!(error.action, "Not Found");
+    assert_eq!(error.status_code, Some(404));
+}
+
+#[test]
+fn deserialize_problem_with_non_string_extensions() {
+    // Arrange
+    let json = r#"{
+        "title": "Out of credit",
+        "balance": 30,
+        "ratio": 0.5,
+        "blocked": true,
+        "account": {"id": 12345},
+        "accounts": ["/account/12345"],
+        "note": null,
+        "region": "eu"
+    }"#;
+
+    // Act
+    let problem: Problem = serde_json::from_str(json).expect("should deserialize");
+
+    // Assert
+    let expected: Vec<(String, String)> = [
+        ("balance", "30"),
+        ("ratio", "0.5"),
+        ("blocked", "true"),
+        ("region", "eu"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_owned(), value.to_owned()))
+    .collect();
+    assert_eq!(problem.extensions, expected);
+}
+
+#[test]
+fn serialize_skips_extensions_colliding_with_members() {
+    // Arrange
+    let mut error = example_error();
+    error.context.push(("status".to_owned(), "ok".to_owned()));
+    error.context.push(("type".to_owned(), "custom".to_owned()));
+
+    // Act
+    let json = serde_json::to_value(Problem::from(&error)).expect("should serialize");
+
+    // Assert
+    assert_eq!(json.get("status"), Some(&serde_json::json!(404)));
+    assert_eq!(json.get("type"), Some(&serde_json::json!("about:blank")));
+    assert_eq!(
+        json.get("path"),
+        Some(&serde_json::json!("/etc/config.yaml"))
+    );
+}
@@ -0,0 +1,122 @@
+use crate::errors::tests::test_helpers::*;
+use crate::{Action, CodeRegistry, DuplicateCode};
+use insta::assert_snapshot;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+enum ConfigAction {
+    #[error("read config")]
+    Read,
+    #[error("parse config")]
+    Parse,
+    #[error("write config")]
+    Write,
+}
+
+impl Action for ConfigAction {
+    fn code(&self) -> Option<String> {
+        let code = match self {
+            Self::Read => "E0001",
+            Self::Parse => "E0002",
+            Self::Write => "E0003",
+        };
+        Some(code.to_owned())
+    }
+
+    fn help(&self) -> Option<String> {
+        match self {
+            Self::Read => Some("Check the file exists and is readable".to_owned()),
+            Self::Parse => Some("Values containing `|` must be quoted".to_owned()),
+            Self::Write => None,
+        }
+    }
+
+    fn url(&self) -> Option<String> {
+        match self {
+            Self::Read => Some("https://docs.example.com/errors/E0001".to_owned()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum CacheAction {
+    #[error("read cache")]
+    Read,
+}
+
+impl Action for CacheAction {
+    fn code(&self) -> Option<String> {
+        Some("E0001".to_owned())
+    }
+}
+
+fn registry() -> CodeRegistry {
+    CodeRegistry::new()
+        .with(ConfigAction::Read)
+        .with(ConfigAction::Parse)
+        .with(ConfigAction::Write)
+}
+
+#[test]
+fn register_collects_entries() {
+    let registry = registry();
+    let entry = registry.entries().first().expect("should have entry");
+    assert_eq!(registry.entries().len(), 3);
+    assert_eq!(entry.code, "E0001");
+    assert_eq!(entry.name, "rogue_logging::ConfigAction::Read");
+    assert_eq!(entry.action, "Failed to read config");
+}
+
+#[test]
+fn register_ignores_repeated_action() {
+    let registry = registry().with(ConfigAction::Read);
+    assert_eq!(registry.entries().len(), 3);
+    assert!(registry.duplicates().is_empty());
+}
+
+#[test]
+fn register_falls_back_to_short_code() {
+    let registry = CodeRegistry::new().with(TestAction::ReadConfig);
+    let entry = registry.entries().first().expect("should have entry");
+    assert_eq!(entry.code, "rogue_logging::TestAction::ReadConfig");
+}
+
+#[test]
+fn duplicates_finds_shared_codes() {
+    let registry = registry().with(CacheAction::Read);
+    assert_eq!(
+        registry.duplicates(),
+        vec![DuplicateCode {
+            code: "E0001".to_owned(),
+            names: vec![
+                "rogue_logging::ConfigAction::Read".to_owned(),
+                "rogue_logging::CacheAction::Read".to_owned(),
+            ],
+        }]
+    );
+}
+
+#[test]
+fn assert_unique_passes_without_duplicates() {
+    registry().assert_unique();
+}
+
+#[test]
+#[should_panic(
+    expected = "E0001: rogue_logging::ConfigAction::Read, rogue_logging::CacheAction::Read"
+)]
+fn assert_unique_panics_on_duplicates() {
+    registry().with(CacheAction::Read).assert_unique();
+}
+
+#[test]
+fn to_markdown_snapshot() {
+    assert_snapshot!(registry().to_markdown());
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn to_json_snapshot() {
+    assert_snapshot!(registry().to_json());
+}
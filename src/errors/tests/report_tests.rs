@@ -0,0 +1,148 @@
+use crate::errors::report::strip_ansi;
+use crate::errors::tests::test_helpers::*;
+use crate::{DiagnosticReport, Failure, Failures, Severity};
+use insta::assert_yaml_snapshot;
+use miette::MietteDiagnostic;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+fn failure() -> Failure<TestAction> {
+    let config = "server:\n  host: localhost\n  port: eighty\n";
+    let inner = Failure::new(TestAction::ParseConfigFile, io_error())
+        .with_path("/etc/myapp/config.yaml")
+        .with("line", "3");
+    let related = Failure::new(
+        HttpAction::CacheUsers,
+        IoError::new(IoErrorKind::ConnectionRefused, "connection refused"),
+    )
+    .with_severity(Severity::Warning)
+    .with_help("Cache is unavailable");
+    Failure::new(TestAction::LoadConfig, inner)
+        .with("format", "yaml")
+        .with_code("config::load")
+        .with_help("Check the configuration file")
+        .with_url("https://docs.example.com/config")
+        .with_source_code("/etc/myapp/config.yaml", config)
+        .with_primary_label((34, 6), "expected an integer")
+        .with_label((0, 6), "in this section")
+        .with_related(related)
+}
+
+fn roundtrip(report: &DiagnosticReport) -> DiagnosticReport {
+    let json = serde_json::to_string(report).expect("should serialize");
+    serde_json::from_str(&json).expect("should deserialize")
+}
+
+#[test]
+fn to_report_snapshot() {
+    use_colors(false);
+    assert_yaml_snapshot!(failure().to_report());
+}
+
+#[test]
+fn to_report_roundtrips_through_serde() {
+    use_colors(false);
+    let report = failure().to_report();
+    assert_eq!(roundtrip(&report), report);
+}
+
+#[test]
+fn rehydrated_report_renders_identically() {
+    use_colors(false);
+    let failure = failure();
+    let rehydrated = roundtrip(&failure.to_report());
+    assert_eq!(render_diagnostic(&rehydrated), render_diagnostic(&failure));
+}
+
+#[test]
+fn rehydrated_report_without_source_renders_identically() {
+    use_colors(false);
+    let failure = Failure::from_action(TestAction::ValidateConfig).with("key", "port");
+    let rehydrated = roundtrip(&failure.to_report());
+    assert_eq!(render_diagnostic(&rehydrated), render_diagnostic(&failure));
+}
+
+#[test]
+fn from_diagnostic_renders_identically() {
+    use_colors(false);
+    let mut failures = Failures::new(TestAction::ValidateConfig);
+    failures.push(Failure::new(TestAction::ReadConfig, io_error()).with("key", "port"));
+    failures.push(Failure::from_action(TestAction::ParseJson));
+    let rehydrated = roundtrip(&DiagnosticReport::from_diagnostic(&failures));
+    assert_eq!(render_diagnostic(&rehydrated), render_diagnostic(&failures));
+}
+
+#[test]
+fn strip_ansi_removes_colors_and_hyperlinks() {
+    let styled = "\x1b[2m▷ path: /etc\x1b[0m \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07";
+    assert_eq!(strip_ansi(styled), "▷ path: /etc link");
+}
+
+#[test]
+fn from_diagnostic_captures_context_of_related_failures() {
+    use_colors(false);
+    let related = Failure::new(TestAction::ReadConfig, io_error()).with_path("/etc/app.yaml");
+    let failure = Failure::from_action(TestAction::LoadConfig).with_related(related);
+    let report = failure.to_report();
+    let child = report.related.first().expect("should have related report");
+    assert_eq!(child.message, "Failed to read config");
+    assert_eq!(
+        child.context,
+        vec![("path".to_owned(), "/etc/app.yaml".to_owned())]
+    );
+}
+
+#[test]
+fn to_report_roundtrips_multi_line_context() {
+    use_colors(false);
+    let related = Failure::new(TestAction::ReadConfig, io_error()).with("query", "a\nb");
+    let failure = Failure::from_action(TestAction::LoadConfig)
+        .with("reason", "first line\nsecond line")
+        .with_related(related);
+    let report = failure.to_report();
+    assert_eq!(
+        report.context,
+        vec![("reason".to_owned(), "first line\nsecond line".to_owned())]
+    );
+    let child = report.related.first().expect("should have related report");
+    assert_eq!(child.context, vec![("query".to_owned(), "a\nb".to_owned())]);
+    let rehydrated = roundtrip(&report);
+    assert_eq!(rehydrated, report);
+    assert_eq!(render_diagnostic(&rehydrated), render_diagnostic(&failure));
+}
+
+#[test]
+fn to_report_captures_context_of_source_failures() {
+    use_colors(false);
+    let inner = Failure::new(TestAction::ParseConfigFile, io_error()).with("line", "3\n4");
+    let failure = Failure::new(TestAction::LoadConfig, inner);
+    let report = failure.to_report();
+    let cause = report.source.as_deref().expect("should have cause");
+    assert_eq!(cause.message, "Failed to parse config file");
+    assert_eq!(cause.context, vec![("line".to_owned(), "3\n4".to_owned())]);
+    let rehydrated = roundtrip(&report);
+    assert_eq!(rehydrated, report);
+    assert_eq!(render_diagnostic(&rehydrated), render_diagnostic(&failure));
+}
+
+#[test]
+fn failures_to_report_captures_context_of_children() {
+    use_colors(false);
+    let mut failures = Failures::new(TestAction::ValidateConfig);
+    failures.push(Failure::new(TestAction::ReadConfig, io_error()).with("key", "a\nb"));
+    let report = failures.to_report();
+    let child = report.related.first().expect("should have related report");
+    assert_eq!(child.message, "Failed to read config");
+    assert_eq!(child.context, vec![("key".to_owned(), "a\nb".to_owned())]);
+    assert_eq!(
+        render_diagnostic(&roundtrip(&report)),
+        render_diagnostic(&failures)
+    );
+}
+
+#[test]
+fn from_diagnostic_keeps_context_like_lines_in_message() {
+    let diagnostic = MietteDiagnostic::new("Failed to sync\n▷ key: value");
+    let report = DiagnosticReport::from_diagnostic(&diagnostic);
+    assert_eq!(report.message, "Failed to sync\n▷ key: value");
+    assert!(report.context.is_empty());
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "miette")]
+use crate::errors::tests::test_helpers::*;
+#[cfg(feature = "miette")]
+use crate::Failure;
+use crate::{is_transient_io_kind, is_transient_status_code, Error};
+#[cfg(all(feature = "log", feature = "miette"))]
+use crate::{retry, RetryPolicy};
+#[cfg(feature = "miette")]
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+#[cfg(feature = "miette")]
+use std::time::Duration;
+
+#[test]
+fn is_transient_io_kind_classifies_kinds() {
+    // Arrange & Act & Assert
+    assert!(is_transient_io_kind(ErrorKind::TimedOut));
+    assert!(is_transient_io_kind(ErrorKind::ConnectionReset));
+    assert!(!is_transient_io_kind(ErrorKind::NotFound));
+    assert!(!is_transient_io_kind(ErrorKind::PermissionDenied));
+}
+
+#[test]
+fn is_transient_status_code_classifies_codes() {
+    // Arrange & Act & Assert
+    assert_eq!(is_transient_status_code(429), Some(true));
+    assert_eq!(is_transient_status_code(503), Some(true));
+    assert_eq!(is_transient_status_code(404), Some(false));
+    assert_eq!(is_transient_status_code(501), Some(false));
+    assert_eq!(is_transient_status_code(200), None);
+}
+
+#[test]
+fn error_is_retryable_infers_from_status_code() {
+    // Arrange
+    let error = Error {
+        status_code: Some(503),
+        ..Error::default()
+    };
+    let explicit = Error {
+        status_code: Some(503),
+        retryable: Some(false),
+        ..Error::default()
+    };
+
+    // Act & Assert
+    assert!(error.is_retryable());
+    assert!(!explicit.is_retryable());
+    assert!(!Error::default().is_retryable());
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn failure_is_retryable_infers_from_source() {
+    // Arrange
+    let timed_out = IoError::new(ErrorKind::TimedOut, "timed out");
+
+    // Act
+    let transient = Failure::new(TestAction::Connect, timed_out);
+    let permanent = Failure::new(TestAction::ReadConfig, io_error());
+
+    // Assert
+    assert!(transient.is_retryable());
+    assert!(!permanent.is_retryable());
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn failure_with_retry_after_is_retryable() {
+    // Arrange & Act
+    let failure =
+        Failure::new(TestAction::FetchData, io_error()).with_retry_after(Duration::from_secs(30));
+
+    // Assert
+    assert!(failure.is_retryable());
+    assert_eq!(failure.retry_after(), Some(Duration::from_secs(30)));
+    assert_eq!(failure.to_error().retry_after, Some(30));
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn to_error_rounds_retry_after_up_to_whole_seconds() {
+    // Arrange
+    let short = Failure::new(TestAction::FetchData, io_error())
+        .with_retry_after(Duration::from_millis(250));
+    let long = Failure::new(TestAction::FetchData, io_error())
+        .with_retry_after(Duration::from_millis(1500));
+
+    // Act & Assert
+    assert_eq!(short.to_error().retry_after, Some(1));
+    assert_eq!(long.to_error().retry_after, Some(2));
+}
+
+#[cfg(all(feature = "log", feature = "miette"))]
+fn immediate_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        delay: Duration::ZERO,
+        multiplier: 2,
+    }
+}
+
+#[test]
+#[cfg(all(feature = "log", feature = "miette"))]
+fn retry_succeeds_after_transient_failures() {
+    // Arrange
+    let policy = immediate_policy();
+
+    // Act
+    let result = retry(&policy, |attempt| {
+        if attempt < 3 {
+            let timed_out = IoError::new(ErrorKind::TimedOut, "timed out");
+            Err(Failure::new(TestAction::Connect, timed_out))
+        } else {
+            Ok(attempt)
+        }
+    });
+
+    // Assert
+    assert_eq!(result.expect("should be Ok"), 3);
+}
+
+#[test]
+#[cfg(all(feature = "log", feature = "miette"))]
+fn retry_attaches_attempts_when_exhausted() {
+    // Arrange
+    let policy = immediate_policy();
+
+    // Act
+    let result: Result<(), _> = retry(&policy, |_| {
+        let timed_out = IoError::new(ErrorKind::TimedOut, "timed out");
+        Err(Failure::new(TestAction::Connect, timed_out))
+    });
+
+    // Assert
+    let failure = result.expect_err("should be Err");
+    assert_eq!(failure.get("attempts"), Some("3".to_owned()));
+}
+
+#[test]
+#[cfg(all(feature = "log", feature = "miette"))]
+fn retry_stops_on_permanent_failure() {
+    // Arrange
+    let policy = immediate_policy();
+
+    // Act
+    let result: Result<(), _> = retry(&policy, |_| {
+        Err(Failure::new(TestAction::ReadConfig, io_error()))
+    });
+
+    // Assert
+    let failure = result.expect_err("should be Err");
+    assert_eq!(failure.get("attempts"), Some("1".to_owned()));
+}
@@ -0,0 +1,124 @@
+use crate::errors::source_location::get_span;
+use crate::errors::tests::test_helpers::*;
+use crate::Failure;
+use insta::assert_snapshot;
+#[cfg(feature = "serde_yaml")]
+use miette::Diagnostic;
+use miette::SourceSpan;
+#[cfg(feature = "serde_yaml")]
+use std::collections::BTreeMap;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+const CONFIG: &str = "server:\n  host: localhost\n  prot: 80\n";
+
+#[test]
+fn get_span_points_at_line_and_column() {
+    assert_eq!(get_span(CONFIG, 1, 1), SourceSpan::from((0, 1)));
+    assert_eq!(get_span(CONFIG, 3, 3), SourceSpan::from((28, 1)));
+}
+
+#[test]
+fn get_span_clamps_column_to_end_of_line() {
+    assert_eq!(get_span(CONFIG, 1, 99), SourceSpan::from((7, 0)));
+}
+
+#[test]
+fn get_span_clamps_line_to_end_of_input() {
+    assert_eq!(get_span(CONFIG, 99, 1), SourceSpan::from((CONFIG.len(), 0)));
+}
+
+#[test]
+fn get_span_treats_column_zero_as_start_of_line() {
+    assert_eq!(get_span(CONFIG, 2, 0), SourceSpan::from((8, 1)));
+}
+
+#[test]
+fn miette_render_with_source_location() {
+    use_colors(false);
+    let source = IoError::new(IoErrorKind::InvalidData, "unknown field `prot`");
+    let failure = Failure::new(TestAction::ParseConfigFile, source).with_source_location(
+        "/etc/myapp/config.yaml",
+        CONFIG,
+        3,
+        3,
+        "unknown field `prot`, expected `host` or `port`",
+    );
+    assert_snapshot!(render_diagnostic(&failure));
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn wrap_with_source_locates_serde_json_error() {
+    use_colors(false);
+    let text = "{\n  \"host\": \"localhost\",\n  \"port\": eighty\n}\n";
+    let failure = serde_json::from_str::<serde_json::Value>(text)
+        .map_err(Failure::wrap_with_source(
+            TestAction::ParseJson,
+            "config.json",
+            text,
+        ))
+        .expect_err("should fail to parse");
+    assert_snapshot!(render_diagnostic(&failure));
+}
+
+#[test]
+#[cfg(feature = "serde_yaml")]
+fn wrap_with_source_locates_serde_yaml_error() {
+    use_colors(false);
+    let text = "server:\n  host: localhost\n  port: [80]\n";
+    let failure = serde_yaml::from_str::<BTreeMap<String, BTreeMap<String, String>>>(text)
+        .map_err(Failure::wrap_with_source(
+            TestAction::ParseConfigFile,
+            "config.yaml",
+            text,
+        ))
+        .expect_err("should fail to parse");
+    assert_snapshot!(render_diagnostic(&failure));
+}
+
+#[test]
+#[cfg(feature = "serde_yaml")]
+fn wrap_with_source_locates_serde_yaml_error_after_non_ascii() {
+    let text = "é€: [1]\n";
+    let failure = serde_yaml::from_str::<BTreeMap<String, String>>(text)
+        .map_err(Failure::wrap_with_source(
+            TestAction::ParseConfigFile,
+            "config.yaml",
+            text,
+        ))
+        .expect_err("should fail to parse");
+    assert_eq!(get_label_offset(&failure), text.find("[1]"));
+
+    let text = "a: {ü: ü, port: [80]}\n";
+    let failure = serde_yaml::from_str::<BTreeMap<String, BTreeMap<String, String>>>(text)
+        .map_err(Failure::wrap_with_source(
+            TestAction::ParseConfigFile,
+            "config.yaml",
+            text,
+        ))
+        .expect_err("should fail to parse");
+    assert_eq!(get_label_offset(&failure), text.find("[80]"));
+}
+
+#[test]
+#[cfg(feature = "toml")]
+fn wrap_with_source_locates_toml_error() {
+    use_colors(false);
+    let text = "[server]\nhost = \"localhost\"\nport = eighty\n";
+    let failure = toml::from_str::<toml::Table>(text)
+        .map_err(Failure::wrap_with_source(
+            TestAction::ParseConfigFile,
+            "config.toml",
+            text,
+        ))
+        .expect_err("should fail to parse");
+    assert_snapshot!(render_diagnostic(&failure));
+}
+
+#[cfg(feature = "serde_yaml")]
+fn get_label_offset(failure: &Failure<TestAction>) -> Option<usize> {
+    failure
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .map(|label| label.offset())
+}
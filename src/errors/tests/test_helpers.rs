@@ -1,6 +1,14 @@
 #[cfg(feature = "miette")]
 use crate::errors::Failure;
+#[cfg(feature = "log")]
+use log::{set_logger, set_max_level, Level, LevelFilter, Log, Metadata, Record};
+#[cfg(feature = "miette")]
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme};
+#[cfg(feature = "log")]
+use std::cell::RefCell;
 use std::io;
+#[cfg(feature = "log")]
+use std::sync::Once;
 use thiserror::Error;
 
 #[allow(unused_variables)]
@@ -12,6 +20,44 @@ pub(crate) fn use_colors(enabled: bool) {
     owo_colors::set_override(enabled);
 }
 
+/// Capture the records logged on this thread while running `action`.
+///
+/// A [`CaptureLogger`] is registered as the global logger on first use.
+#[cfg(feature = "log")]
+pub(crate) fn capture_logs(action: impl FnOnce()) -> Vec<(Level, String)> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        set_logger(&CaptureLogger).expect("no other logger should be registered");
+        set_max_level(LevelFilter::Trace);
+    });
+    RECORDS.with_borrow_mut(Vec::clear);
+    action();
+    RECORDS.take()
+}
+
+#[cfg(feature = "log")]
+thread_local! {
+    static RECORDS: RefCell<Vec<(Level, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Logger that records messages for the current thread without writing them.
+#[cfg(feature = "log")]
+struct CaptureLogger;
+
+#[cfg(feature = "log")]
+impl Log for CaptureLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        RECORDS
+            .with_borrow_mut(|records| records.push((record.level(), record.args().to_string())));
+    }
+
+    fn flush(&self) {}
+}
+
 pub(crate) fn io_error() -> io::Error {
     io::Error::new(io::ErrorKind::NotFound, "file not found")
 }
@@ -28,6 +74,17 @@ pub(crate) fn http_error() -> Failure<HttpAction> {
     Failure::new(HttpAction::CacheUsers, parse).with_path("/var/cache/users.json")
 }
 
+#[cfg(feature = "miette")]
+pub(crate) fn render_diagnostic(diagnostic: &dyn Diagnostic) -> String {
+    let mut output = String::new();
+    let handler =
+        GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor()).with_links(false);
+    handler
+        .render_report(&mut output, diagnostic)
+        .expect("should render diagnostic");
+    output
+}
+
 #[derive(Debug, Error)]
 pub(crate) enum TestAction {
     #[error("read config")]
@@ -50,6 +107,8 @@ pub(crate) enum TestAction {
     ParseConfigFile,
     #[error("get all users")]
     GetAllUsers,
+    #[error("validate config")]
+    ValidateConfig,
 }
 
 #[derive(Debug, Error)]
@@ -59,3 +118,6 @@ pub(crate) enum HttpAction {
     #[error("cache users")]
     CacheUsers,
 }
+
+#[cfg(feature = "miette")]
+crate::impl_action!(TestAction, HttpAction);
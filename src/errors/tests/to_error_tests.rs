@@ -0,0 +1,103 @@
+use crate::errors::tests::test_helpers::io_error;
+use crate::{get_status_code, ResultExt, ToError};
+use std::io::{Error as IoError, ErrorKind};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("wrapper failed")]
+struct WrapperError(#[source] IoError);
+
+impl ToError for WrapperError {}
+
+#[test]
+fn io_error_to_error_infers_domain_and_status_code() {
+    // Arrange
+    let source = io_error();
+
+    // Act
+    let error = source.to_error("read config");
+
+    // Assert
+    assert_eq!(error.action, "read config");
+    assert_eq!(error.message, "file not found");
+    assert_eq!(error.domain, Some("io".to_owned()));
+    assert_eq!(error.status_code, Some(404));
+}
+
+#[test]
+fn custom_error_to_error_collects_causes() {
+    // Arrange
+    let source = WrapperError(io_error());
+
+    // Act
+    let error = source.to_error("read config");
+
+    // Assert
+    assert_eq!(error.message, "wrapper failed");
+    assert_eq!(error.causes, vec!["file not found".to_owned()]);
+    assert!(error.domain.is_none());
+}
+
+#[test]
+fn parse_int_error_to_error_infers_domain() {
+    // Arrange
+    let result = "abc".parse::<u32>();
+
+    // Act
+    let error = result.to_error("parse port").expect_err("should be Err");
+
+    // Assert
+    assert_eq!(error.domain, Some("parse".to_owned()));
+    assert!(error.status_code.is_none());
+}
+
+#[test]
+fn utf8_error_to_error_infers_domain() {
+    // Arrange
+    let result = String::from_utf8(vec![0xFF]);
+
+    // Act
+    let error = result.to_error("decode name").expect_err("should be Err");
+
+    // Assert
+    assert_eq!(error.domain, Some("utf8".to_owned()));
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn serde_json_error_to_error_infers_domain() {
+    // Arrange
+    let result = serde_json::from_str::<u32>("{");
+
+    // Act
+    let error = result
+        .to_error("deserialize object")
+        .expect_err("should be Err");
+
+    // Assert
+    assert_eq!(error.domain, Some("serialization".to_owned()));
+}
+
+#[test]
+fn result_ext_passes_through_ok() {
+    // Arrange
+    let result: Result<u32, IoError> = Ok(42);
+
+    // Act
+    let value = result.to_error("read config").expect("should be Ok");
+
+    // Assert
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn get_status_code_maps_error_kinds() {
+    // Arrange & Act & Assert
+    assert_eq!(get_status_code(ErrorKind::InvalidData), 400);
+    assert_eq!(get_status_code(ErrorKind::PermissionDenied), 403);
+    assert_eq!(get_status_code(ErrorKind::NotFound), 404);
+    assert_eq!(get_status_code(ErrorKind::AlreadyExists), 409);
+    assert_eq!(get_status_code(ErrorKind::ConnectionRefused), 502);
+    assert_eq!(get_status_code(ErrorKind::TimedOut), 504);
+    assert_eq!(get_status_code(ErrorKind::Other), 500);
+}
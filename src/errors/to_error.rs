@@ -0,0 +1,188 @@
+//! Conversions from common std and ecosystem errors into [`Error`].
+
+use super::{is_transient_io_kind, Error, ErrorCategory};
+use std::char::ParseCharError;
+use std::error::Error as StdError;
+use std::io::{Error as IoError, ErrorKind};
+use std::net::AddrParseError;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::{ParseBoolError, Utf8Error};
+use std::string::{FromUtf16Error, FromUtf8Error};
+
+const IO: &str = "io";
+const PARSE: &str = "parse";
+#[cfg(feature = "serde_json")]
+const SERIALIZATION: &str = "serialization";
+const UTF8: &str = "utf8";
+
+/// Conversion of an error into a serializable [`Error`].
+///
+/// Implemented for common std and ecosystem errors with an inferred domain
+/// and, where sensible, a default HTTP status code.
+pub trait ToError: StdError {
+    /// Domain in which this kind of error occurs.
+    ///
+    /// Default: `None`
+    fn error_domain(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// HTTP status code for this kind of error.
+    ///
+    /// Default: `None`
+    fn error_status_code(&self) -> Option<u16> {
+        None
+    }
+
+    /// Category for this kind of error.
+    ///
+    /// Default: `None`
+    fn error_category(&self) -> Option<ErrorCategory> {
+        None
+    }
+
+    /// Whether this kind of error is worth retrying.
+    ///
+    /// Default: `None`
+    fn error_retryable(&self) -> Option<bool> {
+        None
+    }
+
+    /// Convert to a serializable [`Error`] for the action that failed.
+    fn to_error(&self, action: impl Into<String>) -> Error {
+        Error {
+            action: action.into(),
+            message: self.to_string(),
+            domain: self.error_domain().map(ToOwned::to_owned),
+            status_code: self.error_status_code(),
+            category: self.error_category(),
+            retryable: self.error_retryable(),
+            causes: get_causes(self.source()),
+            ..Error::default()
+        }
+    }
+}
+
+/// Extension trait for converting the error of a [`Result`] into an [`Error`].
+pub trait ResultExt<T> {
+    /// Convert the error to a serializable [`Error`] for the action that failed.
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// let config = read_to_string(path).to_error("read config")?;
+    /// ```
+    fn to_error(self, action: impl Into<String>) -> Result<T, Error>;
+}
+
+impl<T, E: ToError> ResultExt<T> for Result<T, E> {
+    fn to_error(self, action: impl Into<String>) -> Result<T, Error> {
+        self.map_err(|error| error.to_error(action))
+    }
+}
+
+impl ToError for IoError {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(IO)
+    }
+
+    fn error_status_code(&self) -> Option<u16> {
+        Some(get_status_code(self.kind()))
+    }
+
+    fn error_category(&self) -> Option<ErrorCategory> {
+        Some(ErrorCategory::from_io_kind(self.kind()))
+    }
+
+    fn error_retryable(&self) -> Option<bool> {
+        Some(is_transient_io_kind(self.kind()))
+    }
+}
+
+impl ToError for ParseIntError {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(PARSE)
+    }
+}
+
+impl ToError for ParseFloatError {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(PARSE)
+    }
+}
+
+impl ToError for ParseBoolError {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(PARSE)
+    }
+}
+
+impl ToError for ParseCharError {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(PARSE)
+    }
+}
+
+impl ToError for AddrParseError {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(PARSE)
+    }
+}
+
+impl ToError for Utf8Error {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(UTF8)
+    }
+}
+
+impl ToError for FromUtf8Error {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(UTF8)
+    }
+}
+
+impl ToError for FromUtf16Error {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(UTF8)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl ToError for serde_json::Error {
+    fn error_domain(&self) -> Option<&'static str> {
+        Some(SERIALIZATION)
+    }
+}
+
+/// Collect the messages of an error and its sources.
+pub(crate) fn get_causes(mut current: Option<&(dyn StdError + 'static)>) -> Vec<String> {
+    let mut causes = Vec::new();
+    while let Some(cause) = current {
+        causes.push(cause.to_string());
+        current = cause.source();
+    }
+    causes
+}
+
+/// Map an [`ErrorKind`] to a default HTTP status code.
+#[must_use]
+pub fn get_status_code(kind: ErrorKind) -> u16 {
+    match kind {
+        ErrorKind::InvalidInput | ErrorKind::InvalidData => 400,
+        ErrorKind::PermissionDenied => 403,
+        ErrorKind::NotFound => 404,
+        ErrorKind::AlreadyExists => 409,
+        ErrorKind::Unsupported => 501,
+        ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionReset
+        | ErrorKind::ConnectionAborted
+        | ErrorKind::NotConnected
+        | ErrorKind::BrokenPipe => 502,
+        ErrorKind::AddrInUse
+        | ErrorKind::AddrNotAvailable
+        | ErrorKind::WouldBlock
+        | ErrorKind::Interrupted => 503,
+        ErrorKind::TimedOut => 504,
+        _ => 500,
+    }
+}
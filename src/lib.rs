@@ -1,8 +1,12 @@
 //! A logging library that provides logs you'll actually want to read.
 //!
 //! - Colorized output with configurable verbosity levels
-//! - Flexible time formats (local, UTC, elapsed, or none)
+//! - Flexible time formats (local, UTC, elapsed, delta, checkpoint, or none)
 //! - Target-based filtering by package name
+//! - Status line spinner and optional `indicatif` progress bar integration
+
+#[cfg(all(test, feature = "derive"))]
+extern crate self as rogue_logging;
 
 mod errors;
 #[cfg(feature = "log")]
@@ -1,10 +1,14 @@
 //! Fluent builder for creating [`Logger`] instances.
 
 use crate::{Logger, LoggerOptions, TimeFormat, Verbosity};
+#[cfg(feature = "indicatif")]
+use indicatif::MultiProgress;
 
 /// Fluent builder for creating and configuring a [`Logger`].
 pub struct LoggerBuilder {
     options: LoggerOptions,
+    #[cfg(feature = "indicatif")]
+    progress: Option<MultiProgress>,
 }
 
 impl LoggerBuilder {
@@ -13,6 +17,8 @@ impl LoggerBuilder {
     pub fn new() -> Self {
         Self {
             options: LoggerOptions::default(),
+            #[cfg(feature = "indicatif")]
+            progress: None,
         }
     }
 
@@ -55,10 +61,41 @@ impl LoggerBuilder {
         self
     }
 
+    /// Set whether to include the thread name in logs.
+    #[must_use]
+    pub fn with_log_thread(mut self, log_thread: bool) -> Self {
+        self.options.log_thread = Some(log_thread);
+        self
+    }
+
+    /// Set whether to log panics through the logger.
+    #[must_use]
+    pub fn with_log_panics(mut self, log_panics: bool) -> Self {
+        self.options.log_panics = Some(log_panics);
+        self
+    }
+
+    /// Set the progress bars to suspend while writing each log line.
+    ///
+    /// Add every [`ProgressBar`](indicatif::ProgressBar) to this [`MultiProgress`]
+    /// so log lines are written above the bars instead of through them.
+    #[cfg(feature = "indicatif")]
+    #[must_use]
+    pub fn with_progress(mut self, progress: MultiProgress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
     /// Build and return the configured [`Logger`].
     #[must_use]
     pub fn create(self) -> Logger {
-        Logger::from(self.options)
+        #[cfg_attr(not(feature = "indicatif"), allow(unused_mut))]
+        let mut logger = Logger::from(self.options);
+        #[cfg(feature = "indicatif")]
+        {
+            logger.progress = self.progress;
+        }
+        logger
     }
 }
 
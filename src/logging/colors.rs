@@ -5,13 +5,26 @@ use colored::{ColoredString, Colorize, CustomColor};
 const GRAY: u8 = 168;
 const DARK_GRAY: u8 = 112;
 
-/// Extension trait for applying custom gray colors to strings.
+/// Distinct colors that avoid the red, yellow, and blue of the log levels.
+const PALETTE: [CustomColor; 6] = [
+    CustomColor::new(102, 194, 165),
+    CustomColor::new(252, 141, 98),
+    CustomColor::new(141, 160, 203),
+    CustomColor::new(231, 138, 195),
+    CustomColor::new(166, 216, 84),
+    CustomColor::new(229, 196, 148),
+];
+
+/// Extension trait for applying custom colors to strings.
 pub trait Colors {
     /// Apply a medium gray color (RGB 168).
     fn gray(&self) -> ColoredString;
 
     /// Apply a dark gray color (RGB 112).
     fn dark_gray(&self) -> ColoredString;
+
+    /// Apply a color from the palette, wrapping the index.
+    fn palette(&self, index: usize) -> ColoredString;
 }
 
 impl Colors for &str {
@@ -22,6 +35,10 @@ impl Colors for &str {
     fn dark_gray(&self) -> ColoredString {
         self.custom_color(CustomColor::new(DARK_GRAY, DARK_GRAY, DARK_GRAY))
     }
+
+    fn palette(&self, index: usize) -> ColoredString {
+        self.custom_color(get_palette_color(index))
+    }
 }
 
 impl Colors for String {
@@ -32,6 +49,10 @@ impl Colors for String {
     fn dark_gray(&self) -> ColoredString {
         self.custom_color(CustomColor::new(DARK_GRAY, DARK_GRAY, DARK_GRAY))
     }
+
+    fn palette(&self, index: usize) -> ColoredString {
+        self.custom_color(get_palette_color(index))
+    }
 }
 
 impl Colors for ColoredString {
@@ -44,4 +65,16 @@ impl Colors for ColoredString {
         self.clone()
             .custom_color(CustomColor::new(DARK_GRAY, DARK_GRAY, DARK_GRAY))
     }
+
+    fn palette(&self, index: usize) -> ColoredString {
+        self.clone().custom_color(get_palette_color(index))
+    }
+}
+
+#[expect(
+    clippy::indexing_slicing,
+    reason = "index is wrapped to the palette length"
+)]
+fn get_palette_color(index: usize) -> CustomColor {
+    PALETTE[index % PALETTE.len()]
 }
@@ -1,6 +1,6 @@
 //! Global logger initialization.
 
-use crate::{Logger, Verbosity};
+use crate::{install_panic_hook, Logger, LoggerOptions};
 use colored::Colorize;
 use log::{set_boxed_logger, set_max_level, trace, Log};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -14,33 +14,41 @@ static IS_INITIALIZED: AtomicBool = AtomicBool::new(false);
 /// already registered. Only the first call has any effect; subsequent calls
 /// are no-ops. This makes it safe to call from multiple tests running in
 /// parallel.
+///
+/// If [`LoggerOptions::log_panics`] is enabled the panic hook is replaced
+/// with one that logs through the logger.
 pub trait InitLog: Log + Sized + 'static {
     fn init(self) -> bool;
 }
 
 impl InitLog for Logger {
     fn init(self) -> bool {
-        let verbosity = self.options.verbosity;
-        init(self, verbosity)
+        let options = self.options.clone();
+        init(self, &options)
     }
 }
 
 impl InitLog for Arc<Logger> {
     fn init(self) -> bool {
-        let verbosity = self.options.verbosity;
-        init(self, verbosity)
+        let options = self.options.clone();
+        init(self, &options)
     }
 }
 
 /// `swap` atomically reads and sets the flag in a single operation, preventing
 /// a race where two threads could both read `false` and both proceed to
 /// initialize.
-fn init(logger: impl Log + 'static, verbosity: Option<Verbosity>) -> bool {
+fn init(logger: impl Log + 'static, options: &LoggerOptions) -> bool {
     if IS_INITIALIZED.swap(true, Ordering::Relaxed) {
         return false;
     }
     match set_boxed_logger(Box::new(logger)) {
-        Ok(()) => set_max_level(verbosity.unwrap_or_default().to_level_filter()),
+        Ok(()) => {
+            set_max_level(options.verbosity.unwrap_or_default().to_level_filter());
+            if options.log_panics.unwrap_or_default() {
+                install_panic_hook();
+            }
+        }
         Err(error) => {
             trace!("{} to initialize the logger: {}", "Failed".bold(), error);
         }
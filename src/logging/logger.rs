@@ -1,19 +1,33 @@
 //! Core logger implementation.
 
-use crate::{Colors, LoggerOptions, TimeFormat, Verbosity};
+use crate::{format_elapsed, Colors, LoggerOptions, Status, TimeFormat, Verbosity};
 use chrono::{Local, Utc};
 use colored::{ColoredString, Colorize};
+#[cfg(feature = "indicatif")]
+use indicatif::MultiProgress;
 use log::*;
 use std::borrow::ToOwned;
-use std::time::SystemTime;
+use std::io::{stderr, IsTerminal, Write};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread::current;
+use std::time::{Duration, SystemTime};
 
 const PACKAGE_NAME: &str = "rogue_logging";
 
+/// Return the cursor to the start of the line and erase it.
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
 /// A logger implementing the [`Log`] trait with colorized, filterable output.
 pub struct Logger {
     /// Configuration options controlling verbosity, time format, and filters.
     pub options: LoggerOptions,
-    start: SystemTime,
+    start: Mutex<SystemTime>,
+    last: Mutex<Option<SystemTime>>,
+    checkpoints: Mutex<Vec<Checkpoint>>,
+    status: Mutex<Option<Status>>,
+    /// Progress bars to suspend while writing each log line.
+    #[cfg(feature = "indicatif")]
+    pub(crate) progress: Option<MultiProgress>,
 }
 
 impl From<LoggerOptions> for Logger {
@@ -21,7 +35,12 @@ impl From<LoggerOptions> for Logger {
     fn from(options: LoggerOptions) -> Self {
         Self {
             options,
-            start: SystemTime::now(),
+            start: Mutex::new(SystemTime::now()),
+            last: Mutex::new(None),
+            checkpoints: Mutex::new(Vec::new()),
+            status: Mutex::new(None),
+            #[cfg(feature = "indicatif")]
+            progress: None,
         }
     }
 }
@@ -38,25 +57,139 @@ impl Logger {
     /// Formatted log prefix containing timestamp, verbosity ID, and icon.
     #[must_use]
     pub fn format_prefix(&self, verbosity: Verbosity) -> String {
+        self.format_prefix_with_icon(verbosity, verbosity.get_icon())
+    }
+
+    /// Formatted log prefix with the verbosity icon replaced.
+    #[must_use]
+    pub(crate) fn format_prefix_with_icon(
+        &self,
+        verbosity: Verbosity,
+        icon: ColoredString,
+    ) -> String {
         let time = self.format_time();
+        let thread = self.format_thread();
         let verbosity_id = verbosity.get_id();
-        let icon = verbosity.get_icon();
-        format!("{time}{verbosity_id} {icon}")
+        format!("{time}{thread}{verbosity_id} {icon}")
+    }
+
+    /// Push a named checkpoint.
+    ///
+    /// [`TimeFormat::Checkpoint`] measures from the most recent checkpoint.
+    pub fn push_checkpoint(&self, name: impl Into<String>) {
+        lock(&self.checkpoints).push(Checkpoint {
+            name: name.into(),
+            time: SystemTime::now(),
+        });
+    }
+
+    /// Remove the most recent checkpoint, returning its name.
+    pub fn pop_checkpoint(&self) -> Option<String> {
+        lock(&self.checkpoints)
+            .pop()
+            .map(|checkpoint| checkpoint.name)
+    }
+
+    /// Reset the start time and remove all checkpoints.
+    ///
+    /// [`TimeFormat::Elapsed`] measures from the start time.
+    pub fn reset(&self) {
+        *lock(&self.start) = SystemTime::now();
+        *lock(&self.last) = None;
+        lock(&self.checkpoints).clear();
+    }
+
+    /// Write a line to stderr without tearing through progress bars or the status line.
+    pub(crate) fn write(&self, line: &str) {
+        self.suspend_progress(|| self.write_around_status(line));
+        *lock(&self.last) = Some(SystemTime::now());
+    }
+
+    /// Hide any progress bars while running the closure, then redraw them.
+    #[cfg_attr(not(feature = "indicatif"), expect(clippy::unused_self))]
+    fn suspend_progress<R>(&self, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "indicatif")]
+        if let Some(progress) = &self.progress {
+            return progress.suspend(f);
+        }
+        f()
+    }
+
+    /// Clear the status line, write the line, then redraw the status line beneath it.
+    fn write_around_status(&self, line: &str) {
+        let status = lock(&self.status);
+        let mut stderr = stderr().lock();
+        let is_drawn = status.is_some() && stderr.is_terminal();
+        if is_drawn {
+            let _ = write!(stderr, "{CLEAR_LINE}");
+        }
+        let _ = writeln!(stderr, "{line}");
+        if is_drawn {
+            if let Some(status) = status.as_ref() {
+                let _ = write!(stderr, "{}", self.format_status(status));
+            }
+        }
+        let _ = stderr.flush();
+    }
+
+    /// Update the status line in place and redraw it.
+    pub(crate) fn update_status(&self, update: impl FnOnce(&mut Option<Status>)) {
+        let mut status = lock(&self.status);
+        update(&mut status);
+        let mut stderr = stderr().lock();
+        if !stderr.is_terminal() {
+            return;
+        }
+        let _ = write!(stderr, "{CLEAR_LINE}");
+        if let Some(status) = status.as_ref() {
+            let _ = write!(stderr, "{}", self.format_status(status));
+        }
+        let _ = stderr.flush();
+    }
+
+    /// Formatted status line containing the log prefix, spinner frame, and message.
+    #[must_use]
+    pub(crate) fn format_status(&self, status: &Status) -> String {
+        let prefix = self.format_prefix_with_icon(Verbosity::Info, status.get_frame());
+        format!("{prefix} {}", status.message)
     }
 
     fn format_time(&self) -> ColoredString {
         let value = match self.options.log_time_format.unwrap_or_default() {
             TimeFormat::Local => Local::now().format("%Y-%m-%d %H:%M:%S%.3f ").to_string(),
             TimeFormat::Utc => Utc::now().format("%Y-%m-%d %H:%M:%S%.3fZ ").to_string(),
-            TimeFormat::Elapsed => format!(
-                "{:>8.3} ",
-                self.start.elapsed().unwrap_or_default().as_secs_f64()
-            ),
+            TimeFormat::Elapsed => format!("{} ", format_elapsed(since(*lock(&self.start)))),
+            TimeFormat::Delta => {
+                let last = lock(&self.last).unwrap_or_else(|| *lock(&self.start));
+                format!("+{} ", format_elapsed(since(last)))
+            }
+            TimeFormat::Checkpoint => match lock(&self.checkpoints).last() {
+                Some(checkpoint) => format!(
+                    "{} {} ",
+                    checkpoint.name,
+                    format_elapsed(since(checkpoint.time))
+                ),
+                None => format!("{} ", format_elapsed(since(*lock(&self.start)))),
+            },
             TimeFormat::None => String::new(),
         };
         value.dark_gray()
     }
 
+    fn format_thread(&self) -> String {
+        if !self.options.log_thread.unwrap_or_default() {
+            return String::new();
+        }
+        let name = get_thread_name();
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "truncation is harmless when choosing a palette color"
+        )]
+        let index = hash_name(&name) as usize;
+        format!("{} ", name.palette(index))
+    }
+
     fn exclude_by_target(&self, target: &str) -> bool {
         if let Some(exclude_filters) = self.options.log_exclude_filters.clone() {
             for filter in exclude_filters {
@@ -76,7 +209,7 @@ impl Logger {
         false
     }
 
-    fn exclude_by_verbosity(&self, verbosity: Verbosity) -> bool {
+    pub(crate) fn exclude_by_verbosity(&self, verbosity: Verbosity) -> bool {
         verbosity.as_num() > self.options.verbosity.unwrap_or_default().as_num()
     }
 }
@@ -88,17 +221,52 @@ impl Log for Logger {
         !self.exclude_by_target(target) && !self.exclude_by_verbosity(verbosity)
     }
 
-    #[expect(clippy::print_stderr)]
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
             let verbosity = Verbosity::from_level(record.level());
             let message = format!("{}", record.args());
             let log = self.format_log(verbosity, message);
-            eprintln!("{log}");
+            self.write(&log);
         }
     }
 
-    fn flush(&self) {}
+    fn flush(&self) {
+        let _ = stderr().flush();
+    }
+}
+
+/// A named point in time to measure from.
+struct Checkpoint {
+    name: String,
+    time: SystemTime,
+}
+
+/// Hash a name with 64-bit FNV-1a.
+///
+/// Unlike [`std::hash::DefaultHasher`] the algorithm is fixed, so each name
+/// keeps its palette color across Rust releases.
+pub(crate) fn hash_name(name: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    name.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Name of the current thread, falling back to its ID.
+pub(crate) fn get_thread_name() -> String {
+    let thread = current();
+    thread
+        .name()
+        .map_or_else(|| format!("{:?}", thread.id()), ToOwned::to_owned)
+}
+
+fn since(time: SystemTime) -> Duration {
+    time.elapsed().unwrap_or_default()
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
 }
 
 fn format_message(verbosity: Verbosity, message: String) -> String {
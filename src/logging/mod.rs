@@ -5,9 +5,14 @@ mod colors;
 mod init;
 mod logger;
 mod options;
+mod panic_hook;
+#[cfg(feature = "miette")]
+mod run;
+mod status;
 #[cfg(test)]
 mod tests;
 mod time_format;
+mod timer;
 mod verbosity;
 
 pub use builder::*;
@@ -15,5 +20,10 @@ pub use colors::*;
 pub use init::*;
 pub use logger::*;
 pub use options::*;
+pub use panic_hook::*;
+#[cfg(feature = "miette")]
+pub use run::*;
+pub use status::*;
 pub use time_format::*;
+pub use timer::*;
 pub use verbosity::*;
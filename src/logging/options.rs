@@ -22,4 +22,14 @@ pub struct LoggerOptions {
 
     /// Exclude logs from specific packages
     pub log_exclude_filters: Option<Vec<String>>,
+
+    /// Include the thread name, or ID if unnamed, in logs.
+    ///
+    /// Default: `false`
+    pub log_thread: Option<bool>,
+
+    /// Log panics through the logger instead of the default panic hook.
+    ///
+    /// Default: `false`
+    pub log_panics: Option<bool>,
 }
@@ -0,0 +1,62 @@
+//! Panic hook that logs panics through the `log` facade.
+
+use crate::{get_backtrace, get_thread_name};
+use colored::Colorize;
+use log::{error, log_enabled, logger, trace, Level};
+use std::panic::{set_hook, take_hook, Location, PanicHookInfo};
+
+/// Replace the panic hook with one that logs panics through the `log` facade.
+///
+/// The panic message, location, and thread are logged at the error level and
+/// the backtrace, if captured, at the trace level. All sinks are flushed
+/// before the hook returns.
+///
+/// If the logger is disabled for the error level, for example by
+/// [`Verbosity::Silent`](crate::Verbosity::Silent) or a target filter, the
+/// previous hook is called instead so the panic is never lost.
+///
+/// This is installed by [`InitLog::init`](crate::InitLog::init) if
+/// [`LoggerOptions::log_panics`](crate::LoggerOptions::log_panics) is enabled.
+pub fn install_panic_hook() {
+    let previous = take_hook();
+    set_hook(Box::new(move |info| {
+        if log_enabled!(Level::Error) {
+            log_panic(info);
+        } else {
+            previous(info);
+        }
+    }));
+}
+
+fn log_panic(info: &PanicHookInfo) {
+    let message = get_payload_message(info);
+    for line in panic_lines(&message, info.location(), &get_thread_name()) {
+        error!("{line}");
+    }
+    if let Some(backtrace) = get_backtrace() {
+        trace!("Backtrace:\n{backtrace}");
+    }
+    logger().flush();
+}
+
+/// Format the panic as separate lines.
+pub(crate) fn panic_lines(message: &str, location: Option<&Location>, thread: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    lines.push(format!("{} in thread '{thread}'", "Panicked".bold()));
+    if let Some(location) = location {
+        lines.push(format!("at {location}"));
+    }
+    lines.push(message.to_owned());
+    lines
+}
+
+fn get_payload_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
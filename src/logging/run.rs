@@ -0,0 +1,45 @@
+//! Entry point helper for binaries that report a [`Failure`].
+
+use crate::{Action, DiagnosticExt, Failure, InitLog, Logger, LoggerOptions};
+use log::logger;
+use std::process::ExitCode;
+
+/// Initialize the logger, run the closure, and report any [`Failure`].
+///
+/// The failure is rendered to stderr, with color only if the logger is
+/// colorizing, and the exit code is chosen by its category.
+///
+/// # Example
+///
+/// ```text
+/// fn main() -> ExitCode {
+///     run(LoggerOptions::default(), || {
+///         let config = read_to_string(path).map_err(Failure::wrap(Action::ReadConfig))?;
+///         Ok(())
+///     })
+/// }
+/// ```
+pub fn run<T: Action, V>(
+    options: LoggerOptions,
+    main: impl FnOnce() -> Result<V, Failure<T>>,
+) -> ExitCode {
+    Logger::from(options).init();
+    report(main())
+}
+
+/// Report any [`Failure`] of a result and choose the exit code.
+///
+/// The failure is rendered to stderr, with color only if the logger is
+/// colorizing, and the exit code is chosen by its category. Unlike [`run`]
+/// the logger is not initialized.
+#[expect(clippy::print_stderr)]
+pub fn report<T: Action, V>(result: Result<V, Failure<T>>) -> ExitCode {
+    match result {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(failure) => {
+            logger().flush();
+            eprint!("{}", failure.render());
+            ExitCode::from(failure.get_category().exit_code())
+        }
+    }
+}
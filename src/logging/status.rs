@@ -0,0 +1,89 @@
+//! Single line status with a spinner drawn beneath the log output.
+
+use crate::{Logger, Verbosity};
+use colored::{ColoredString, Colorize};
+
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// State of the status line.
+pub(crate) struct Status {
+    pub(crate) message: String,
+    frame: usize,
+}
+
+impl Status {
+    /// Create a status at the first spinner frame.
+    pub(crate) fn new(message: String) -> Self {
+        Self { message, frame: 0 }
+    }
+
+    /// Advance to the next spinner frame.
+    pub(crate) fn tick(&mut self) {
+        self.frame = (self.frame + 1) % FRAMES.len();
+    }
+
+    /// Get the colorized spinner frame.
+    #[must_use]
+    pub(crate) fn get_frame(&self) -> ColoredString {
+        FRAMES.get(self.frame).unwrap_or(&" ").blue()
+    }
+}
+
+/// A spinner shown as a status line beneath the log output.
+///
+/// The status line is only drawn when stderr is a terminal. Log lines written
+/// by the [`Logger`] clear the status line and redraw it afterwards.
+///
+/// The status line is cleared when the spinner is dropped.
+pub struct Spinner<'a> {
+    logger: &'a Logger,
+}
+
+impl Logger {
+    /// Show a spinner with a message as the status line.
+    ///
+    /// To use the spinner while the logger is registered globally, share
+    /// the logger as an `Arc<Logger>` and initialize with that.
+    #[must_use]
+    pub fn spinner(&self, message: impl Into<String>) -> Spinner<'_> {
+        let status = Status::new(message.into());
+        self.update_status(|current| *current = Some(status));
+        Spinner { logger: self }
+    }
+}
+
+impl Spinner<'_> {
+    /// Replace the message.
+    pub fn set_message(&self, message: impl Into<String>) {
+        let message = message.into();
+        self.logger.update_status(|status| {
+            if let Some(status) = status {
+                status.message = message;
+            }
+        });
+    }
+
+    /// Advance the spinner by one frame.
+    pub fn tick(&self) {
+        self.logger.update_status(|status| {
+            if let Some(status) = status {
+                status.tick();
+            }
+        });
+    }
+
+    /// Clear the status line and log a final message at the info level.
+    pub fn finish(self, message: impl Into<String>) {
+        self.logger.update_status(|status| *status = None);
+        if !self.logger.exclude_by_verbosity(Verbosity::Info) {
+            let log = self.logger.format_log(Verbosity::Info, message.into());
+            self.logger.write(&log);
+        }
+    }
+}
+
+impl Drop for Spinner<'_> {
+    fn drop(&mut self) {
+        self.logger.update_status(|status| *status = None);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::{LoggerBuilder, LoggerOptions, TimeFormat, Verbosity};
+#[cfg(feature = "indicatif")]
+use indicatif::{MultiProgress, ProgressDrawTarget};
 
 #[test]
 fn new_creates_builder_with_defaults() {
@@ -10,6 +12,8 @@ fn new_creates_builder_with_defaults() {
     assert_eq!(logger.options.log_time_format, None);
     assert_eq!(logger.options.log_include_filters, None);
     assert_eq!(logger.options.log_exclude_filters, None);
+    assert_eq!(logger.options.log_thread, None);
+    assert_eq!(logger.options.log_panics, None);
 }
 
 #[test]
@@ -20,6 +24,8 @@ fn with_options_sets_all_options() {
         log_time_format: Some(TimeFormat::Utc),
         log_include_filters: Some(vec!["foo".to_owned()]),
         log_exclude_filters: Some(vec!["bar".to_owned()]),
+        log_thread: Some(true),
+        log_panics: Some(true),
     };
 
     // Act
@@ -36,6 +42,8 @@ fn with_options_sets_all_options() {
         logger.options.log_exclude_filters,
         Some(vec!["bar".to_owned()])
     );
+    assert_eq!(logger.options.log_thread, Some(true));
+    assert_eq!(logger.options.log_panics, Some(true));
 }
 
 #[test]
@@ -102,3 +110,34 @@ fn with_exclude_filter_adds_filter() {
         Some(vec!["noisy_crate".to_owned()])
     );
 }
+
+#[test]
+fn with_log_thread_sets_log_thread() {
+    // Arrange & Act
+    let logger = LoggerBuilder::new().with_log_thread(true).create();
+
+    // Assert
+    assert_eq!(logger.options.log_thread, Some(true));
+}
+
+#[test]
+fn with_log_panics_sets_log_panics() {
+    // Arrange & Act
+    let logger = LoggerBuilder::new().with_log_panics(true).create();
+
+    // Assert
+    assert_eq!(logger.options.log_panics, Some(true));
+}
+
+#[test]
+#[cfg(feature = "indicatif")]
+fn with_progress_sets_progress() {
+    // Arrange
+    let progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+
+    // Act
+    let logger = LoggerBuilder::new().with_progress(progress).create();
+
+    // Assert
+    assert!(logger.progress.is_some());
+}
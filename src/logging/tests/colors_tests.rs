@@ -53,3 +53,17 @@ fn dark_gray_applies_to_colored_string() {
     // Assert
     assert_snapshot!(result.to_string());
 }
+
+#[test]
+fn palette_wraps_index() {
+    // Arrange
+    force_truecolor();
+
+    // Act
+    let first = TEXT.palette(0);
+    let wrapped = TEXT.palette(6);
+
+    // Assert
+    assert_eq!(first.to_string(), wrapped.to_string());
+    assert_ne!(first.to_string(), TEXT.palette(1).to_string());
+}
@@ -1,7 +1,9 @@
 use super::test_helpers::force_truecolor;
+use crate::logging::logger::{get_thread_name, hash_name};
 use crate::{LoggerBuilder, TimeFormat, Verbosity};
 use insta::assert_snapshot;
 use log::{Level, Metadata};
+use std::thread::Builder;
 
 #[test]
 fn format_log_with_time_format_local() {
@@ -185,3 +187,137 @@ fn format_prefix_contains_verbosity_id() {
     // Assert
     assert!(prefix.contains("INFO"));
 }
+
+#[test]
+fn format_prefix_with_time_format_delta() {
+    // Arrange
+    let logger = LoggerBuilder::new()
+        .with_time_format(TimeFormat::Delta)
+        .create();
+
+    // Act
+    let prefix = logger.format_prefix(Verbosity::Info);
+
+    // Assert
+    assert!(prefix.contains("+   0.0"));
+}
+
+#[test]
+fn format_prefix_with_time_format_checkpoint() {
+    // Arrange
+    let logger = LoggerBuilder::new()
+        .with_time_format(TimeFormat::Checkpoint)
+        .create();
+    logger.push_checkpoint("extract");
+    logger.push_checkpoint("transform");
+
+    // Act
+    let transform = logger.format_prefix(Verbosity::Info);
+    let popped = logger.pop_checkpoint();
+    let extract = logger.format_prefix(Verbosity::Info);
+
+    // Assert
+    assert!(transform.contains("transform    0.0"));
+    assert_eq!(popped, Some("transform".to_owned()));
+    assert!(extract.contains("extract    0.0"));
+}
+
+#[test]
+fn reset_removes_checkpoints() {
+    // Arrange
+    let logger = LoggerBuilder::new()
+        .with_time_format(TimeFormat::Checkpoint)
+        .create();
+    logger.push_checkpoint("extract");
+
+    // Act
+    logger.reset();
+
+    // Assert
+    assert!(!logger.format_prefix(Verbosity::Info).contains("extract"));
+    assert_eq!(logger.pop_checkpoint(), None);
+}
+
+#[test]
+fn format_prefix_contains_thread_name() {
+    // Arrange
+    let logger = LoggerBuilder::new()
+        .with_time_format(TimeFormat::None)
+        .with_log_thread(true)
+        .create();
+
+    // Act
+    let prefix = Builder::new()
+        .name("worker".to_owned())
+        .spawn(move || logger.format_prefix(Verbosity::Info))
+        .expect("thread should spawn")
+        .join()
+        .expect("thread should join");
+
+    // Assert
+    assert!(prefix.contains("worker"));
+}
+
+#[test]
+fn format_prefix_excludes_thread_name_by_default() {
+    // Arrange
+    let logger = LoggerBuilder::new()
+        .with_time_format(TimeFormat::None)
+        .create();
+
+    // Act
+    let prefix = Builder::new()
+        .name("worker".to_owned())
+        .spawn(move || logger.format_prefix(Verbosity::Info))
+        .expect("thread should spawn")
+        .join()
+        .expect("thread should join");
+
+    // Assert
+    assert!(!prefix.contains("worker"));
+}
+
+#[test]
+fn get_thread_name_returns_name() {
+    // Arrange
+    let thread = Builder::new()
+        .name("worker".to_owned())
+        .spawn(get_thread_name)
+        .expect("thread should spawn");
+
+    // Act
+    let name = thread.join().expect("thread should join");
+
+    // Assert
+    assert_eq!(name, "worker");
+}
+
+#[test]
+fn get_thread_name_falls_back_to_id() {
+    // Arrange
+    let thread = Builder::new()
+        .spawn(get_thread_name)
+        .expect("thread should spawn");
+
+    // Act
+    let name = thread.join().expect("thread should join");
+
+    // Assert
+    assert!(name.starts_with("ThreadId("));
+}
+
+#[test]
+fn hash_name_is_fnv1a() {
+    // Arrange & Act
+    let hashes = [hash_name(""), hash_name("a"), hash_name("foobar")];
+
+    // Assert
+    assert_eq!(
+        hashes,
+        [
+            0xcbf2_9ce4_8422_2325,
+            0xaf63_dc4c_8601_ec8c,
+            0x8594_4171_f739_67e8
+        ]
+    );
+}
@@ -2,6 +2,11 @@ mod builder_tests;
 mod colors_tests;
 mod logger_tests;
 mod options_tests;
+mod panic_hook_tests;
+#[cfg(feature = "miette")]
+mod run_tests;
+mod status_tests;
 pub(crate) mod test_helpers;
 mod time_format_tests;
+mod timer_tests;
 mod verbosity_tests;
@@ -10,4 +10,6 @@ fn default_has_none_values() {
     assert!(options.log_time_format.is_none());
     assert!(options.log_include_filters.is_none());
     assert!(options.log_exclude_filters.is_none());
+    assert!(options.log_thread.is_none());
+    assert!(options.log_panics.is_none());
 }
@@ -0,0 +1,31 @@
+use crate::logging::panic_hook::panic_lines;
+use std::panic::Location;
+
+#[test]
+fn panic_lines_include_thread_location_and_message() {
+    // Arrange
+    let location = Location::caller();
+
+    // Act
+    let lines = panic_lines("index out of bounds", Some(location), "worker");
+
+    // Assert
+    assert_eq!(lines.len(), 3);
+    assert!(lines
+        .first()
+        .is_some_and(|line| line.ends_with(" in thread 'worker'")));
+    assert_eq!(lines.get(1), Some(&format!("at {location}")));
+    assert_eq!(
+        lines.get(2).map(String::as_str),
+        Some("index out of bounds")
+    );
+}
+
+#[test]
+fn panic_lines_without_location() {
+    // Arrange & Act
+    let lines = panic_lines("index out of bounds", None, "worker");
+
+    // Assert
+    assert_eq!(lines.len(), 2);
+}
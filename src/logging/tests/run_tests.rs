@@ -0,0 +1,33 @@
+use crate::{impl_action, report, Failure};
+use std::io::{Error as IoError, ErrorKind};
+use std::process::ExitCode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+enum RunAction {
+    #[error("read config")]
+    ReadConfig,
+}
+
+impl_action!(RunAction);
+
+#[test]
+fn report_returns_success_for_ok() {
+    // Arrange & Act
+    let exit_code = report(Ok::<u32, Failure<RunAction>>(42));
+
+    // Assert
+    assert_eq!(exit_code, ExitCode::SUCCESS);
+}
+
+#[test]
+fn report_returns_category_exit_code_for_failure() {
+    // Arrange
+    let source = IoError::new(ErrorKind::NotFound, "file not found");
+
+    // Act
+    let exit_code = report::<_, ()>(Err(Failure::new(RunAction::ReadConfig, source)));
+
+    // Assert
+    assert_eq!(exit_code, ExitCode::from(66));
+}
@@ -0,0 +1,38 @@
+use super::test_helpers::force_truecolor;
+use crate::{LoggerBuilder, Status, TimeFormat};
+use insta::assert_snapshot;
+
+#[test]
+fn format_status_replaces_icon_with_spinner_frame() {
+    // Arrange
+    force_truecolor();
+    let logger = LoggerBuilder::new()
+        .with_time_format(TimeFormat::None)
+        .create();
+    let status = Status::new("Downloading".to_owned());
+
+    // Act
+    let output = logger.format_status(&status);
+
+    // Assert
+    assert_snapshot!(output);
+}
+
+#[test]
+fn tick_advances_and_wraps_frame() {
+    // Arrange
+    let mut status = Status::new("Downloading".to_owned());
+    let first = status.get_frame().to_string();
+
+    // Act
+    status.tick();
+    let second = status.get_frame().to_string();
+    for _ in 1..10 {
+        status.tick();
+    }
+    let wrapped = status.get_frame().to_string();
+
+    // Assert
+    assert_ne!(first, second);
+    assert_eq!(first, wrapped);
+}
@@ -1,4 +1,5 @@
-use crate::TimeFormat;
+use crate::{format_elapsed, TimeFormat};
+use std::time::Duration;
 
 #[test]
 fn default_is_local() {
@@ -8,3 +9,12 @@ fn default_is_local() {
     // Assert
     assert!(matches!(default, TimeFormat::Local));
 }
+
+#[test]
+fn format_elapsed_pads_seconds() {
+    // Arrange & Act
+    let formatted = format_elapsed(Duration::from_millis(1234));
+
+    // Assert
+    assert_eq!(formatted, "   1.234");
+}
@@ -0,0 +1,90 @@
+use crate::{timer, TimerBuilder, Verbosity};
+use std::time::Duration;
+
+#[test]
+fn format_started_includes_action() {
+    // Arrange
+    let timer = TimerBuilder::new("extract archive").start();
+
+    // Act
+    let message = timer.format_started();
+
+    // Assert
+    assert_eq!(message, "Starting to extract archive");
+}
+
+#[test]
+fn format_completed_uses_verbosity_below_threshold() {
+    // Arrange
+    let timer = TimerBuilder::new("extract archive")
+        .with_verbosity(Verbosity::Debug)
+        .with_threshold(Duration::from_secs(5))
+        .start();
+
+    // Act
+    let (verbosity, message) = timer.format_completed(Duration::from_millis(1234));
+
+    // Assert
+    assert_eq!(verbosity, Verbosity::Debug);
+    assert_eq!(message, "Took 1.234s to extract archive");
+}
+
+#[test]
+fn format_completed_warns_above_threshold() {
+    // Arrange
+    let timer = TimerBuilder::new("extract archive")
+        .with_threshold(Duration::from_secs(5))
+        .start();
+
+    // Act
+    let (verbosity, message) = timer.format_completed(Duration::from_secs(6));
+
+    // Assert
+    assert_eq!(verbosity, Verbosity::Warn);
+    assert_eq!(
+        message,
+        "Took 6.000s to extract archive, exceeding the 5.000s threshold"
+    );
+}
+
+#[test]
+fn format_panicked_includes_elapsed() {
+    // Arrange
+    let timer = TimerBuilder::new("extract archive").start();
+
+    // Act
+    let message = timer.format_panicked(Duration::from_millis(42));
+
+    // Assert
+    assert!(message.contains("Failed"));
+    assert!(message.ends_with(" to extract archive after 0.042s due to a panic"));
+}
+
+#[test]
+fn finish_returns_elapsed() {
+    // Arrange
+    let timer = timer!(verbosity: Verbosity::Trace, "transform {} rows", 3);
+
+    // Act
+    let elapsed = timer.finish();
+
+    // Assert
+    assert!(elapsed < Duration::from_secs(5));
+}
+
+#[test]
+fn timer_macro_ignores_caller_format_macro() {
+    // Arrange
+    #[expect(unused_macros, reason = "would shadow std format if captured")]
+    macro_rules! format {
+        ($($arg:tt)+) => {
+            compile_error!("caller format macro was used")
+        };
+    }
+
+    // Act
+    let timer = timer!(verbosity: Verbosity::Trace, "load {} files", 2);
+
+    // Assert
+    assert!(timer.finish() < Duration::from_secs(5));
+}
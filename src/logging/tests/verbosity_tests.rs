@@ -41,3 +41,14 @@ fn default_is_info() {
     // Assert
     assert_eq!(default, Verbosity::Info);
 }
+
+#[test]
+fn to_level_converts_correctly() {
+    // Arrange & Act & Assert
+    assert_eq!(Verbosity::Silent.to_level(), None);
+    assert_eq!(Verbosity::Error.to_level(), Some(Level::Error));
+    assert_eq!(Verbosity::Warn.to_level(), Some(Level::Warn));
+    assert_eq!(Verbosity::Info.to_level(), Some(Level::Info));
+    assert_eq!(Verbosity::Debug.to_level(), Some(Level::Debug));
+    assert_eq!(Verbosity::Trace.to_level(), Some(Level::Trace));
+}
@@ -2,6 +2,7 @@
 
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Timestamp format for log output.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, ValueEnum)]
@@ -20,6 +21,25 @@ pub enum TimeFormat {
     ///
     /// Example: `30020.289`
     Elapsed,
+    /// Elapsed time since the previous log line in seconds with millisecond precision.
+    ///
+    /// Example: `+   0.042`
+    Delta,
+    /// Elapsed time since the most recent checkpoint in seconds with millisecond precision,
+    /// prefixed by the checkpoint name.
+    ///
+    /// Falls back to [`TimeFormat::Elapsed`] if no checkpoint has been pushed.
+    ///
+    /// Example: `extract   12.345`
+    Checkpoint,
     /// No timestamp.
     None,
 }
+
+/// Format a duration in seconds with millisecond precision, right aligned.
+///
+/// Example: `   1.234`
+#[must_use]
+pub fn format_elapsed(duration: Duration) -> String {
+    format!("{:>8.3}", duration.as_secs_f64())
+}
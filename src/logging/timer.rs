@@ -0,0 +1,189 @@
+//! Timers that log the duration of scoped operations.
+
+use crate::{format_elapsed, Verbosity};
+use colored::Colorize;
+use log::log;
+use std::thread::panicking;
+use std::time::{Duration, Instant};
+
+/// Fluent builder for creating and starting a [`Timer`].
+pub struct TimerBuilder {
+    action: String,
+    verbosity: Verbosity,
+    threshold: Option<Duration>,
+    target: &'static str,
+}
+
+impl TimerBuilder {
+    /// Create a new builder for an action.
+    ///
+    /// The action is a concise description of the operation that typically
+    /// starts with a verb.
+    ///
+    /// Example: `extract archive`
+    #[must_use]
+    pub fn new(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            verbosity: Verbosity::Info,
+            threshold: None,
+            target: module_path!(),
+        }
+    }
+
+    /// Set the verbosity to log the start and completion at.
+    ///
+    /// Default: `info`
+    #[must_use]
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Set the duration above which completion is logged at the warn level.
+    ///
+    /// Default: `None`
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: Duration) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Set the target to log with.
+    ///
+    /// Default: `rogue_logging::logging::timer`
+    #[must_use]
+    pub fn with_target(mut self, target: &'static str) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Log the start of the action and return the running [`Timer`].
+    #[must_use]
+    pub fn start(self) -> Timer {
+        let timer = Timer {
+            action: self.action,
+            verbosity: self.verbosity,
+            threshold: self.threshold,
+            target: self.target,
+            start: Instant::now(),
+            is_finished: false,
+        };
+        timer.log(timer.verbosity, &timer.format_started());
+        timer
+    }
+}
+
+/// A running timer that logs the duration of an action when finished or dropped.
+///
+/// - Completion is logged at the configured verbosity.
+/// - Completion exceeding the threshold is logged at the warn level.
+/// - Dropping during a panic is logged at the error level.
+pub struct Timer {
+    action: String,
+    verbosity: Verbosity,
+    threshold: Option<Duration>,
+    target: &'static str,
+    start: Instant,
+    is_finished: bool,
+}
+
+impl Timer {
+    /// Time elapsed since the timer started.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Log the completion of the action and return the elapsed time.
+    #[expect(
+        clippy::must_use_candidate,
+        reason = "finishing is useful without the duration"
+    )]
+    pub fn finish(mut self) -> Duration {
+        let elapsed = self.elapsed();
+        let (verbosity, message) = self.format_completed(elapsed);
+        self.log(verbosity, &message);
+        self.is_finished = true;
+        elapsed
+    }
+
+    pub(crate) fn format_started(&self) -> String {
+        format!("Starting to {}", self.action)
+    }
+
+    pub(crate) fn format_completed(&self, elapsed: Duration) -> (Verbosity, String) {
+        let took = format!("Took {}s to {}", format_seconds(elapsed), self.action);
+        match self.threshold {
+            Some(threshold) if elapsed > threshold => (
+                Verbosity::Warn,
+                format!(
+                    "{took}, exceeding the {}s threshold",
+                    format_seconds(threshold)
+                ),
+            ),
+            _ => (self.verbosity, took),
+        }
+    }
+
+    pub(crate) fn format_panicked(&self, elapsed: Duration) -> String {
+        format!(
+            "{} to {} after {}s due to a panic",
+            "Failed".bold(),
+            self.action,
+            format_seconds(elapsed)
+        )
+    }
+
+    fn log(&self, verbosity: Verbosity, message: &str) {
+        if let Some(level) = verbosity.to_level() {
+            log!(target: self.target, level, "{message}");
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if self.is_finished {
+            return;
+        }
+        let elapsed = self.elapsed();
+        if panicking() {
+            self.log(Verbosity::Error, &self.format_panicked(elapsed));
+        } else {
+            let (verbosity, message) = self.format_completed(elapsed);
+            self.log(verbosity, &message);
+        }
+    }
+}
+
+/// Format a duration consistently with [`TimeFormat::Elapsed`](crate::TimeFormat::Elapsed).
+fn format_seconds(duration: Duration) -> String {
+    format_elapsed(duration).trim_start().to_owned()
+}
+
+/// Start a [`Timer`] that logs the duration of the enclosing scope.
+///
+/// The action is formatted like [`format!`] and logged with the caller's
+/// module path as the target.
+///
+/// # Example
+///
+/// ```text
+/// let _timer = timer!("extract {}", path.display());
+/// let _timer = timer!(verbosity: Verbosity::Debug, "transform {count} rows");
+/// ```
+#[macro_export]
+macro_rules! timer {
+    (verbosity: $verbosity:expr, $($arg:tt)+) => {
+        $crate::TimerBuilder::new(::std::format!($($arg)+))
+            .with_target(::std::module_path!())
+            .with_verbosity($verbosity)
+            .start()
+    };
+    ($($arg:tt)+) => {
+        $crate::TimerBuilder::new(::std::format!($($arg)+))
+            .with_target(::std::module_path!())
+            .start()
+    };
+}
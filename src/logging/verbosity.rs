@@ -84,4 +84,19 @@ impl Verbosity {
             Trace => LevelFilter::Trace,
         }
     }
+
+    /// Convert to a [`log::Level`].
+    ///
+    /// Returns `None` for [`Verbosity::Silent`].
+    #[must_use]
+    pub(crate) fn to_level(self) -> Option<Level> {
+        match self {
+            Silent => None,
+            Error => Some(Level::Error),
+            Warn => Some(Level::Warn),
+            Info => Some(Level::Info),
+            Debug => Some(Level::Debug),
+            Trace => Some(Level::Trace),
+        }
+    }
 }
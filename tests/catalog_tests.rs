@@ -0,0 +1,48 @@
+//! Process-wide message catalog, isolated from the unit tests because it
+//! replaces the catalog for every thread.
+
+use rogue_logging::{get_message_catalog, set_message_catalog, Error, MessageCatalog, Problem};
+
+struct GermanCatalog;
+
+impl MessageCatalog for GermanCatalog {
+    fn failed(&self) -> String {
+        "Fehler".to_owned()
+    }
+
+    fn failed_to(&self, failed: &str, action: &str) -> String {
+        format!("{failed} beim {action}")
+    }
+
+    fn caused_by(&self, cause: &str) -> String {
+        format!("Verursacht durch: {cause}")
+    }
+}
+
+#[test]
+fn set_message_catalog_replaces_the_active_catalog() {
+    // Arrange
+    set_message_catalog(GermanCatalog);
+    let error = Error {
+        action: "Laden der Konfiguration".to_owned(),
+        message: "Datei nicht gefunden".to_owned(),
+        causes: vec!["No such file or directory".to_owned()],
+        ..Error::default()
+    };
+
+    // Act
+    let failed = get_message_catalog().failed();
+    let display = error.display();
+    let problem = Problem::from(&error);
+    let parsed = Error::from(problem.clone());
+
+    // Assert
+    assert_eq!(failed, "Fehler");
+    assert!(display.contains(" beim Laden der Konfiguration"));
+    assert!(display.contains("Verursacht durch: No such file or directory"));
+    assert_eq!(
+        problem.title.as_deref(),
+        Some("Fehler beim Laden der Konfiguration")
+    );
+    assert_eq!(parsed.action, error.action);
+}
@@ -0,0 +1,30 @@
+//! Process-wide panic hook behavior, isolated from the unit tests because it
+//! registers the global logger.
+#![cfg(feature = "log")]
+
+use rogue_logging::{InitLog, LoggerBuilder, Verbosity};
+use std::panic::{catch_unwind, set_hook};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PREVIOUS_HOOK_CALLED: AtomicBool = AtomicBool::new(false);
+
+#[test]
+#[expect(clippy::panic, reason = "testing the panic hook")]
+fn init_installs_hook_that_falls_back_when_logger_is_silent() {
+    // Arrange
+    set_hook(Box::new(|_| {
+        PREVIOUS_HOOK_CALLED.store(true, Ordering::Relaxed);
+    }));
+    let logger = LoggerBuilder::new()
+        .with_verbosity(Verbosity::Silent)
+        .with_log_panics(true)
+        .create();
+    assert!(logger.init());
+
+    // Act
+    let result = catch_unwind(|| panic!("lost panic"));
+
+    // Assert
+    assert!(result.is_err());
+    assert!(PREVIOUS_HOOK_CALLED.load(Ordering::Relaxed));
+}